@@ -30,20 +30,203 @@
 //! assert_eq!(&["id", "name", "mimeType"], File::fields());
 //! ```
 //!
+//! `field_count`/`variant_count` return the number of names as a `const fn`.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct File {
+//!     id: String,
+//!     #[fievar(skip)]
+//!     cache: Vec<u8>,
+//!     name: String,
+//! }
+//!
+//! const N: usize = File::field_count();
+//! let lens: [usize; N] = [0; File::field_count()];
+//! assert_eq!(2, lens.len());
+//! assert_eq!(File::fields().len(), N);
+//! ```
+//!
+//! Raw identifiers lose their `r#`.
+//! ```rust
+//! use fievar::{Fields, Variants};
+//!
+//! #[derive(Fields)]
+//! struct Token {
+//!     r#type: String,
+//!     r#match: bool,
+//!     #[fievar(name = "r#loop")]
+//!     r#loop: u8,
+//! }
+//!
+//! #[derive(Variants)]
+//! #[allow(non_camel_case_types)]
+//! enum Keyword {
+//!     r#struct,
+//!     #[fievar(transform = "C")]
+//!     r#async,
+//! }
+//!
+//! assert_eq!(&["type", "match", "r#loop"], Token::fields());
+//! assert_eq!(&["struct", "ASYNC"], Keyword::variants());
+//! ```
+//!
+//! Unit structs have no fields.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Marker;
+//!
+//! assert!(Marker::fields().is_empty());
+//! ```
+//!
+//! Unions have named fields too.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! union Bits {
+//!     int: u32,
+//!     #[fievar(name = "f32")]
+//!     float: f32,
+//! }
+//!
+//! assert_eq!(&["int", "f32"], Bits::fields());
+//! ```
+//!
+//! ```compile_fail
+//! #[derive(fievar::Fields)]
+//! enum Format { Json }
+//! ```
+//!
+//! Generic types work too, bounds and `where` clauses included.
+//! ```rust
+//! use fievar::Variants;
+//!
+//! #[derive(Variants)]
+//! enum Either<L, R: Default>
+//! where
+//!     L: Clone,
+//! {
+//!     Left(L),
+//!     Right(R),
+//! }
+//!
+//! assert_eq!(&["Left", "Right"], Either::<u8, String>::variants());
+//! ```
+//!
+//! Only the variant name is used, whatever data the variant carries.
+//! ```rust
+//! use fievar::Variants;
+//!
+//! #[derive(Variants)]
+//! enum Event {
+//!     Quit,
+//!     Key(char, bool),
+//!     Move { x: i32, y: i32 },
+//! }
+//!
+//! assert_eq!(&["Quit", "Key", "Move"], Event::variants());
+//! ```
+//!
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Wrapper<T> {
+//!     inner: T,
+//!     tag: String,
+//! }
+//!
+//! #[derive(Fields)]
+//! struct Borrowed<'a> {
+//!     name: &'a str,
+//! }
+//!
+//! #[derive(Fields)]
+//! struct Bounded<K, V>
+//! where
+//!     K: Ord,
+//!     V: Clone,
+//! {
+//!     key: K,
+//!     value: V,
+//! }
+//!
+//! assert_eq!(&["inner", "tag"], Wrapper::<u8>::fields());
+//! assert_eq!(&["name"], Borrowed::fields());
+//! assert_eq!(&["key", "value"], Bounded::<u8, String>::fields());
+//! ```
+//!
+//! `Names` works on both structs and enums, generating a `names` method either way. The
+//! container attributes of `Fields` or `Variants` apply, and other generated methods are
+//! named after `names`.
+//! ```rust
+//! use fievar::Names;
+//!
+//! #[derive(Names)]
+//! #[fievar(transform = "C|_")]
+//! struct File {
+//!     mime_type: String,
+//! }
+//!
+//! #[derive(Names)]
+//! #[fievar(iter)]
+//! enum Format {
+//!     Json,
+//!     Toml,
+//! }
+//!
+//! assert_eq!(&["MIME_TYPE"], File::names());
+//! assert_eq!(&["Json", "Toml"], Format::names());
+//! assert_eq!(2, Format::name_count());
+//! assert_eq!(vec!["Toml"], Format::names_where(|n| n.starts_with('T')));
+//! ```
+//!
+//! ```compile_fail
+//! use fievar::Names;
+//!
+//! #[derive(Names)]
+//! union Bits {
+//!     int: u32,
+//!     float: f32,
+//! }
+//! ```
+//!
+//! Attribute values are given with `=`, so `#[fievar(name("mimeType"))]` is an error
+//! suggesting `name = "mimeType"` instead.
+//! ```compile_fail
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct File {
+//!     #[fievar(name("mimeType"))]
+//!     mime_type: String,
+//! }
+//! ```
+//!
 //! # Transformations
 //! ## Syntax
 //! *Expression:*  
-//! &ensp;&ensp; [[*T*][`|`*Sep*]]
+//! &ensp;&ensp; [[*T*][`|`*Sep*[`|`*Sep*]]]
 //!
 //! *Sep:* Word separator.  
-//! &ensp;&ensp; Can be any text.
+//! &ensp;&ensp; Can be any text. `\|`, written `"\\|"` in a string literal, is a literal `|`.
 //!
 //! *T:*
 //! Determines how to transform field/variant.  
 //! &ensp;&ensp; [[*TrCase*][` `*NumAlign*]]
 //!
 //! *NumAlign:* Controls alignment of numerals.  
-//! &ensp;&ensp; `1__` | `__1` | `_1_`  
+//! &ensp;&ensp; (`1__` | `__1` | `_1_`)[`:`*NumWidth*][`=`*NumSep*]
+//!
+//! *NumWidth:* Zero-pads numerals to at least this many digits.  
+//! &ensp;&ensp; A decimal number.
+//!
+//! *NumSep:* Separator used next to numeral words instead of *Sep*.  
+//! &ensp;&ensp; Can be any text without spaces.
 //!
 //! *TrCase:* Controls the case of letters.  
 //! &ensp;&ensp; [*TrWord*[` `*TrWord*[` `*TrWord*]]]  
@@ -52,7 +235,7 @@
 //! &ensp;&ensp; [*TrChar*[*TrChar*[*TrChar*]]]
 //!
 //! *TrChar:* Controls the case of individual characters in words.  
-//! &ensp;&ensp; `c` | `C`  
+//! &ensp;&ensp; `c` | `C` | `v` | `V`  
 //!
 //! *TrCase* consists of upto three *TrWord*s separated by a space. If there is
 //! only one *TrWord* then it is used to transform all words in field/variant. If
@@ -63,170 +246,3960 @@
 //! applied to the rest of the words. *TrChar*s work similarly on characters in a
 //! word.
 //!
-//! ## Examples
+//! `c` and `C` lowercase and uppercase all characters. `V` uppercases vowels and
+//! lowercases consonants, `v` does the opposite.
 //! ```rust
 //! use fievar::Variants;
 //!
 //! #[derive(Variants)]
-//! enum E {
-//!     #[fievar(transform = "c")] // lowercase
-//!     AVeryLong0Variant,
+//! enum Greeting {
+//!     #[fievar(transform = "V")]
+//!     Hello,
+//!     #[fievar(transform = "v|_")]
+//!     HelloWorld,
+//! }
 //!
-//!     #[fievar(transform = "C")] // uppercase
-//!     AVeryLong1Variant,
+//! assert_eq!(&["hEllO", "HeLLo_WoRLD"], Greeting::variants());
+//! ```
 //!
-//!     #[fievar(transform = "1__|_")] // align numeral left
-//!     AVeryLong2Variant,
+//! The middle *TrWord* applies to every word between the first and the last, however many
+//! there are. A fourth *TrWord* is an error.
+//! ```rust
+//! use fievar::Fields;
 //!
-//!     #[fievar(transform = "__1|_")] // align numeral right
-//!     AVeryLong3Variant,
+//! #[derive(Fields)]
+//! struct Words {
+//!     #[fievar(transform = "CcC cCc CcC|_")]
+//!     one_two_three_four_five: u8,
+//!     #[fievar(transform = "CcC cCc CcC|_")]
+//!     one_two: u8,
+//! }
 //!
-//!     #[fievar(transform = "_1_|_")] // align numeral middle
-//!     AVeryLong4Variant,
+//! assert_eq!(&["OnE_tWo_tHREe_fOUr_FivE", "OnE_TwO"], Words::fields());
+//! ```
 //!
-//!     #[fievar(transform = "c Cc")] // camelCase
-//!     AVeryLong5Variant,
+//! ```compile_fail
+//! use fievar::Fields;
 //!
-//!     #[fievar(transform = "c|_")] // snake_case
-//!     AVeryLong6Variant,
+//! #[derive(Fields)]
+//! struct Words {
+//!     #[fievar(transform = "C c C c|_")]
+//!     one_two_three_four: u8,
+//! }
+//! ```
 //!
-//!     #[fievar(transform = "CcC cCc CcC _1_|*-*")] // something different
-//!     LastVeryLong7Variant,
+//! In a one-letter word the first letter is also the last, and the first *TrChar* wins. A
+//! *TrWord* like `Cc` title-cases every word.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Letters {
+//!     #[fievar(transform = "Cc")]
+//!     a: u8,
+//!     #[fievar(transform = "CcC")]
+//!     b: u8,
+//!     #[fievar(transform = "cCC")]
+//!     c: u8,
+//!     #[fievar(transform = "cCC|_")]
+//!     x_yz: u8,
+//!     #[fievar(transform = "Cc| ")]
+//!     title_case_with_spaces: u8,
 //! }
 //!
-//! let v = &[
-//!     "averylong0variant",
-//!     "AVERYLONG1VARIANT",
-//!     "A_Very_Long2_Variant",
-//!     "A_Very_Long_3Variant",
-//!     "A_Very_Long_4_Variant",
-//!     "aVeryLong5Variant",
-//!     "a_very_long6_variant",
-//!     "LasT*-*vERy*-*lONg*-*7*-*VarianT"
-//! ];
-//! assert_eq!(v, E::variants());
+//! let v = &["A", "B", "c", "x_yZ", "Title Case With Spaces"];
+//! assert_eq!(v, Letters::fields());
 //! ```
-
-mod types;
-mod utils;
-
-use proc_macro::TokenStream;
-use proc_macro_error::{abort, proc_macro_error};
-use quote::quote;
-use syn::{
-    parse_macro_input, Attribute, Data, DataEnum, DataStruct, DeriveInput, Fields, FieldsNamed,
-    Ident, Lit, LitStr, Meta, MetaList, MetaNameValue, NestedMeta,
-};
-use types::{ExprParser, FievarParser, Tr, TrChars};
-
-const FIEVAR: &str = "fievar";
-
-macro_rules! gen_impl {
-    ($strenm:expr, $fievars:expr, $fn_name:ident) => {{
-        let fievars = $fievars;
-        let strenm = $strenm;
-
-        quote! (
-            impl #strenm {
-                pub fn $fn_name() -> &'static [&'static str] {
-                    &[#(#fievars),*]
-                }
-            }
-        )
-    }};
+//!
+//! A name may start with a numeral, as after a `name`. No word comes before it, so it
+//! stays with the word after it unless numerals are aligned to the middle. Numerals later
+//! in the name, even right after a separator, follow the alignment as usual.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Scene {
+//!     #[fievar(name = "3dModel", transform = "c|_")]
+//!     model: u8,
+//!     #[fievar(name = "3dModel", transform = "c _1_|_")]
+//!     model_middle: u8,
+//!     #[fievar(name = "2FA", transform = "c|_")]
+//!     two_factor: u8,
+//!     #[fievar(name = "42", transform = "c|_")]
+//!     answer: u8,
+//!     #[fievar(name = "a_2b", transform = "c|_")]
+//!     after_under: u8,
+//!     #[fievar(name = "my_2nd_item", transform = "c|-")]
+//!     second: u8,
+//! }
+//!
+//! assert_eq!(
+//!     &["3d_model", "3_d_model", "2fa", "42", "a_2_b", "my-2-nd-item"],
+//!     Scene::fields()
+//! );
+//! ```
+//!
+//! Names made only of separators have no words, so a transform turns them into an empty
+//! string.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(transform = "c|_")]
+//! #[allow(non_snake_case)]
+//! struct Blank {
+//!     #[fievar(name = "_", transform = "c|_")]
+//!     a: u8,
+//!     __: u8,
+//!     _A: u8,
+//!     #[fievar(name = "", transform = "c|_")]
+//!     b: u8,
+//! }
+//!
+//! assert_eq!(&["", "", "a", ""], Blank::fields());
+//! ```
+//!
+//! An invalid character is reported with its position, and a caret under it in the
+//! expression.
+//! ```compile_fail
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Item {
+//!     #[fievar(transform = "c Cx Cc|_")] // invalid character 'x' at 3
+//!     item_name: String,
+//! }
+//! ```
+//!
+//! A *NumSep* is used on either side of words that are all digits.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Item {
+//!     #[fievar(transform = "c _1_=-|_")]
+//!     item3name: String,
+//!     #[fievar(transform = "c _1_=-|_")]
+//!     item3BigName: String,
+//!     #[fievar(transform = "c _1_=|_")]
+//!     item4BigName: String,
+//! }
+//!
+//! assert_eq!(&["item-3-name", "item-3-big_name", "item4big_name"], Item::fields());
+//! ```
+//!
+//! A `|` that is part of a separator is escaped with a backslash.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Pipes {
+//!     #[fievar(transform = "c|\\|")]
+//!     first_name: String,
+//!     #[fievar(transform = "c| \\| ")]
+//!     last_name: String,
+//! }
+//!
+//! assert_eq!(&["first|name", "last | name"], Pipes::fields());
+//! ```
+//!
+//! With two separators the first joins the first two words and the second joins the rest.
+//! A *NumSep* still takes precedence next to numerals.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Paths {
+//!     #[fievar(transform = "c|::|-")]
+//!     std_io_error: String,
+//!     #[fievar(transform = "c _1_=.|::|-")]
+//!     v2_io_error: String,
+//!     #[fievar(transform = "c|::|-")]
+//!     single: String,
+//! }
+//!
+//! assert_eq!(&["std::io-error", "v.2.io-error", "single"], Paths::fields());
+//! ```
+//!
+//! ```compile_fail
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Paths {
+//!     #[fievar(transform = "c|::|-|.")] // at most two separators
+//!     std_io_error: String,
+//! }
+//! ```
+//!
+//! A *NumWidth* pads every numeral with leading zeros so names sort lexically. Numerals
+//! that already have as many digits or more are left as they are.
+//! ```rust
+//! use fievar::Variants;
+//!
+//! #[derive(Variants)]
+//! #[fievar(transform = "c __1:3")]
+//! enum Version {
+//!     V2,
+//!     V13,
+//!     V1024,
+//! }
+//!
+//! assert_eq!(&["v002", "v013", "v1024"], Version::variants());
+//! ```
+//!
+//! ```compile_fail
+//! use fievar::Variants;
+//!
+//! #[derive(Variants)]
+//! #[fievar(transform = "c __1:")] // missing width
+//! enum Version { V2 }
+//! ```
+//!
+//! Words are separated by `_`, `-`, case changes and numerals. With the
+//! `preserve_separators` flag the words are joined with the separators found in the
+//! name instead of *Sep*.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct File {
+//!     #[fievar(name = "my-field_name", transform = "Cc", preserve_separators)]
+//!     a: String,
+//!     #[fievar(transform = "C|-", preserve_separators)]
+//!     mimeType: String,
+//! }
+//!
+//! assert_eq!(&["My-Field_Name", "MIMETYPE"], File::fields());
+//! ```
+//!
+//! It only changes how a transform joins words, so it is an error without a `transform` or
+//! `case`.
+//! ```compile_fail
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct File {
+//!     #[fievar(name = "my-field_name", preserve_separators)]
+//!     field: String,
+//! }
+//! ```
+//!
+//! `case` is a shorthand for common transforms: `snake` (`c|_`), `camel` (`c Cc`), `pascal`
+//! (`Cc`), `kebab` (`c|-`), `screaming_snake` (`C|_`) and `screaming_kebab` (`C|-`). It can't
+//! be used together with `transform`.
+//! ```rust
+//! use fievar::Variants;
+//!
+//! #[derive(Variants)]
+//! enum Format {
+//!     #[fievar(case = "snake")]
+//!     JsonLines,
+//!     #[fievar(case = "camel")]
+//!     MessagePack,
+//!     #[fievar(case = "screaming_kebab")]
+//!     PlainText,
+//! }
+//!
+//! assert_eq!(&["json_lines", "messagePack", "PLAIN-TEXT"], Format::variants());
+//! ```
+//!
+//! ```compile_fail
+//! use fievar::Variants;
+//!
+//! #[derive(Variants)]
+//! enum Format {
+//!     #[fievar(case = "snake", transform = "C")]
+//!     JsonLines,
+//! }
+//! ```
+//!
+//! A `name` always sets the base a field/variant `transform` or `case` works on, whichever
+//! is written first.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Record {
+//!     #[fievar(name = "mimeType", transform = "c|_")]
+//!     a: String,
+//!     #[fievar(transform = "c|_", name = "mimeType")]
+//!     b: String,
+//!     #[fievar(case = "kebab", name = "fileSize")]
+//!     c: u64,
+//! }
+//!
+//! assert_eq!(&["mime_type", "mime_type", "file-size"], Record::fields());
+//! ```
+//!
+//! `upper_first` uppercases only the first word matching the given one, ignoring case.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Record {
+//!     #[fievar(upper_first = "data")]
+//!     data_data_value: String,
+//!     #[fievar(transform = "c Cc", upper_first = "data")]
+//!     meta_data_data: String,
+//! }
+//!
+//! assert_eq!(&["DATA_data_value", "metaDATAData"], Record::fields());
+//! ```
+//!
+//! Transforms work on characters rather than bytes, so names may contain any characters.
+//! Characters without a case never start a new word.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Menu {
+//!     #[fievar(transform = "CcC")]
+//!     élan: String,
+//!     #[fievar(name = "😀smile", transform = "CcC")]
+//!     smile: String,
+//!     #[fievar(name = "team🚀Rocket", transform = "cC cCc|_")]
+//!     rocket: String,
+//!     #[fievar(name = "é", transform = "CcC")]
+//!     e: String,
+//! }
+//!
+//! assert_eq!(&["ÉlaN", "😀smilE", "tEAM🚀_rOCKEt", "É"], Menu::fields());
+//! ```
+//!
+//! Each character is converted on its own, even when its case mapping is longer than one
+//! character.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Street {
+//!     #[fievar(name = "ßtraße", transform = "CcC")]
+//!     a: String,
+//!     #[fievar(name = "aßb", transform = "cCc")]
+//!     b: String,
+//! }
+//!
+//! assert_eq!(&["SStraßE", "aSSb"], Street::fields());
+//! ```
+//!
+//! A run of uppercase letters followed by a lowercase letter is split before the last
+//! uppercase letter, so acronyms become words of their own.
+//! ```rust
+//! use fievar::Variants;
+//!
+//! #[derive(Variants)]
+//! #[fievar(transform = "c|_")]
+//! enum Acronyms {
+//!     XMLHttpRequest,
+//!     IOError,
+//!     AString,
+//!     HTTPSConnection,
+//!     UseHTTP,
+//!     ID,
+//! }
+//!
+//! let v = &["xml_http_request", "io_error", "a_string", "https_connection", "use_http", "id"];
+//! assert_eq!(v, Acronyms::variants());
+//! ```
+//!
+//! Separators are applied the same way around numerals whatever the separator is.
+//! ```rust
+//! use fievar::Variants;
+//!
+//! #[derive(Variants)]
+//! enum Server {
+//!     #[fievar(transform = "C|-")]
+//!     MyHTTP2Server,
+//!     #[fievar(transform = "C __1|-")]
+//!     MyHTTP2ServerA,
+//!     #[fievar(transform = "C _1_|-")]
+//!     MyHTTP2ServerB,
+//!     #[fievar(transform = "c _1_|-")]
+//!     MyHTTPServer2,
+//!     #[fievar(transform = "c|-")]
+//!     MyHttp2Server,
+//! }
+//!
+//! let v = &[
+//!     "MY-HTTP2-SERVER",
+//!     "MY-HTTP-2SERVER-A",
+//!     "MY-HTTP-2-SERVER-B",
+//!     "my-http-server-2",
+//!     "my-http2-server",
+//! ];
+//! assert_eq!(v, Server::variants());
+//! ```
+//!
+//! `wrap_first` and `wrap_last` wrap the first or last word of a transformed name in the
+//! given text on both sides.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[allow(non_snake_case)]
+//! struct Row {
+//!     #[fievar(transform = "Cc c|_", wrap_first = "**")]
+//!     UserId: u32,
+//!     #[fievar(transform = "c|_", wrap_last = "|")]
+//!     created_at_utc: u64,
+//! }
+//!
+//! assert_eq!(&["**User**_id", "created_at_|utc|"], Row::fields());
+//! ```
+//!
+//! Without a `transform` or `case` there are no words, so they are an error.
+//! ```compile_fail
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Row {
+//!     #[fievar(wrap_first = "**")]
+//!     user_id: u32,
+//! }
+//! ```
+//!
+//! `describe_transform!` expands to a description of a transform, which helps when reading
+//! or documenting one. Invalid transforms are compile errors.
+//! ```rust
+//! use fievar::describe_transform;
+//!
+//! assert_eq!(
+//!     "lowercase all words, join with '_', keep numerals with the word before them",
+//!     describe_transform!("c|_"),
+//! );
+//! assert_eq!(
+//!     "lowercase the first word, uppercase the first letter and lowercase the other letters \
+//!      of the other words, join without a separator, keep numerals as words of their own",
+//!     describe_transform!("c Cc _1_"),
+//! );
+//! assert_eq!(
+//!     "uppercase the vowels and lowercase the consonants of all words, join with '-', keep \
+//!      numerals with the word after them, join numerals with '.'",
+//!     describe_transform!("V __1=.|-"),
+//! );
+//! assert_eq!(
+//!     "lowercase all words, join without a separator, keep numerals with the word after \
+//!      them, zero-pad numerals to 3 digits",
+//!     describe_transform!("c __1:3"),
+//! );
+//! assert_eq!(
+//!     "lowercase all words, join the first two words with '::', join with '-', keep \
+//!      numerals with the word before them",
+//!     describe_transform!("c|::|-"),
+//! );
+//! ```
+//!
+//! ```compile_fail
+//! fievar::describe_transform!("x");
+//! ```
+//!
+//! `fievar_check!` only checks a transform and expands to nothing, so it can be used in
+//! item or statement position to validate transforms kept elsewhere.
+//! ```rust
+//! fievar::fievar_check!("c Cc|_");
+//!
+//! fn main() {
+//!     fievar::fievar_check!("C __1:2=.|-");
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! fievar::fievar_check!("c Cx|_");
+//! ```
+//!
+//! ## Examples
+//! ```rust
+//! use fievar::Variants;
+//!
+//! #[derive(Variants)]
+//! enum E {
+//!     #[fievar(transform = "c")] // lowercase
+//!     AVeryLong0Variant,
+//!
+//!     #[fievar(transform = "C")] // uppercase
+//!     AVeryLong1Variant,
+//!
+//!     #[fievar(transform = "1__|_")] // align numeral left
+//!     AVeryLong2Variant,
+//!
+//!     #[fievar(transform = "__1|_")] // align numeral right
+//!     AVeryLong3Variant,
+//!
+//!     #[fievar(transform = "_1_|_")] // align numeral middle
+//!     AVeryLong4Variant,
+//!
+//!     #[fievar(transform = "c Cc")] // camelCase
+//!     AVeryLong5Variant,
+//!
+//!     #[fievar(transform = "c|_")] // snake_case
+//!     AVeryLong6Variant,
+//!
+//!     #[fievar(transform = "CcC cCc CcC _1_|*-*")] // something different
+//!     LastVeryLong7Variant,
+//! }
+//!
+//! let v = &[
+//!     "averylong0variant",
+//!     "AVERYLONG1VARIANT",
+//!     "A_Very_Long2_Variant",
+//!     "A_Very_Long_3Variant",
+//!     "A_Very_Long_4_Variant",
+//!     "aVeryLong5Variant",
+//!     "a_very_long6_variant",
+//!     "LasT*-*vERy*-*lONg*-*7*-*VarianT"
+//! ];
+//! assert_eq!(v, E::variants());
+//! ```
+//!
+//! Each run of digits is aligned on its own. `1__` keeps it with the word before, `_1_`
+//! makes it a word and `__1` keeps it with the word after, or with the word before when
+//! nothing follows.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Versions {
+//!     #[fievar(name = "V2Point5", transform = "c 1__|_")]
+//!     a: u8,
+//!     #[fievar(name = "V2Point5", transform = "c _1_|_")]
+//!     b: u8,
+//!     #[fievar(name = "V2Point5", transform = "c __1|_")]
+//!     c: u8,
+//!     #[fievar(name = "V2Point5Beta", transform = "c 1__|_")]
+//!     d: u8,
+//!     #[fievar(name = "V2Point5Beta", transform = "c _1_|_")]
+//!     e: u8,
+//!     #[fievar(name = "V2Point5Beta", transform = "c __1|_")]
+//!     f: u8,
+//!     #[fievar(name = "V22Point55", transform = "c 1__|_")]
+//!     g: u8,
+//!     #[fievar(name = "V22Point55", transform = "c _1_|_")]
+//!     h: u8,
+//!     #[fievar(name = "V22Point55", transform = "c __1|_")]
+//!     i: u8,
+//!     #[fievar(name = "v2point5", transform = "c 1__|_")]
+//!     j: u8,
+//!     #[fievar(name = "v2point5", transform = "c _1_|_")]
+//!     k: u8,
+//!     #[fievar(name = "v2point5", transform = "c __1|_")]
+//!     l: u8,
+//! }
+//!
+//! let v = &[
+//!     "v2_point5",
+//!     "v_2_point_5",
+//!     "v_2point5",
+//!     "v2_point5_beta",
+//!     "v_2_point_5_beta",
+//!     "v_2point_5beta",
+//!     "v22_point55",
+//!     "v_22_point_55",
+//!     "v_22point55",
+//!     "v2_point5",
+//!     "v_2_point_5",
+//!     "v_2point5",
+//! ];
+//! assert_eq!(v, Versions::fields());
+//! ```
+//!
+//! # Container attributes
+//! These are applied to the struct or enum itself.
+//!
+//! ## `use_serde`
+//! Uses the name from `#[serde(rename = "..")]` for fields/variants without their own `name`
+//! or `transform`. The serde name is used as is, like a `name`, so it is not converted by
+//! `transform`, `heck` or `rename_all`.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(use_serde, rename_all = "SCREAMING_SNAKE_CASE")]
+//! struct File {
+//!     #[serde(rename = "mimeType")]
+//!     mime_type: String,
+//!     #[serde(rename = "fileSize")]
+//!     #[fievar(name = "size")]
+//!     file_size: u64,
+//!     #[serde(rename(serialize = "createdAt", deserialize = "created"))]
+//!     created_at: u64,
+//!     owner_id: u32,
+//! }
+//!
+//! assert_eq!(&["mimeType", "size", "createdAt", "OWNER_ID"], File::fields());
+//! ```
+//!
+//! `#[serde(rename_all = "..")]` on the container is used unless `heck` or `rename_all` is
+//! given. It follows serde's own rules rather than those of `rename_all`, so the names match
+//! what serde produces: variants are assumed to be in PascalCase and split before every
+//! uppercase letter, and fields are assumed to be in snake_case and split only at `_`.
+//! ```rust
+//! use fievar::Variants;
+//!
+//! #[derive(Variants)]
+//! #[fievar(use_serde)]
+//! #[serde(rename_all = "kebab-case")]
+//! enum Event {
+//!     UserJoined,
+//!     #[serde(rename = "bye")]
+//!     UserLeft,
+//! }
+//!
+//! assert_eq!(&["user-joined", "bye"], Event::variants());
+//! ```
+//!
+//! ```rust
+//! use fievar::{Fields, Variants};
+//!
+//! #[derive(Variants)]
+//! #[fievar(use_serde)]
+//! #[serde(rename_all = "snake_case")]
+//! enum Failure {
+//!     IOError,
+//!     HTTPServer,
+//! }
+//!
+//! #[derive(Fields)]
+//! #[fievar(use_serde)]
+//! #[serde(rename_all = "PascalCase")]
+//! struct Settings {
+//!     v2config: u8,
+//!     max_retry_count: u8,
+//! }
+//!
+//! assert_eq!(&["i_o_error", "h_t_t_p_server"], Failure::variants());
+//! assert_eq!(&["V2config", "MaxRetryCount"], Settings::fields());
+//! ```
+//!
+//! The derives register `serde` as a helper attribute, so `use_serde` works without serde's
+//! own derives. `serde` attributes are only read with `use_serde` and are otherwise ignored,
+//! and fievar never checks them, so without serde's derives a misspelled `#[serde(..)]` is
+//! accepted silently.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[serde(rename_all = "camelCase")]
+//! struct File {
+//!     #[serde(rename = "mimeType")]
+//!     mime_type: String,
+//!     file_size: u64,
+//! }
+//!
+//! assert_eq!(&["mime_type", "file_size"], File::fields());
+//! ```
+//!
+//! ## `transform`
+//! A default transform for every field/variant. Fields/variants with their own `name` or
+//! `transform` use that instead.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(transform = "c|_")]
+//! #[allow(non_snake_case)]
+//! struct File {
+//!     mimeType: String,
+//!     #[fievar(transform = "C|-")]
+//!     createdAt: u64,
+//!     #[fievar(name = "ID")]
+//!     id: String,
+//!     #[fievar(wrap_first = "*")]
+//!     fileSize: u64,
+//! }
+//!
+//! assert_eq!(&["mime_type", "CREATED-AT", "ID", "*file*_size"], File::fields());
+//! ```
+//!
+//! `transform`, `heck`, `rename_all`, `title_case` and, under `use_serde`, serde's own
+//! `rename_all` each rename every name, so only one of them can be given.
+//! ```compile_fail
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(heck = "snake", transform = "C|-")]
+//! struct File {
+//!     mime_type: String,
+//! }
+//! ```
+//!
+//! ## `fn`
+//! Renames the generated `fields`/`variants` method. Everything else keeps its name.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(fn = "json_keys", iter)]
+//! struct File {
+//!     id: String,
+//!     mime_type: String,
+//! }
+//!
+//! impl File {
+//!     fn fields(&self) -> usize {
+//!         2
+//!     }
+//! }
+//!
+//! assert_eq!(&["id", "mime_type"], File::json_keys());
+//! assert_eq!(vec!["mime_type"], File::fields_where(|n| n.starts_with("mime")));
+//! ```
+//!
+//! The name must be a valid identifier.
+//! ```compile_fail
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(fn = "json keys")]
+//! struct File {
+//!     id: String,
+//! }
+//! ```
+//!
+//! ## `impl_trait`
+//! A proc macro crate can't export traits, so to use the names generically declare a trait
+//! with a `fields`/`variants` method like below and name it here. The derive implements it
+//! by calling the inherent method.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! pub trait HasFields {
+//!     fn fields() -> &'static [&'static str];
+//! }
+//!
+//! #[derive(Fields)]
+//! #[fievar(impl_trait = "HasFields")]
+//! struct File {
+//!     id: String,
+//! }
+//!
+//! #[derive(Fields)]
+//! #[fievar(impl_trait = "crate::HasFields")]
+//! struct Wrapper<T> {
+//!     inner: T,
+//! }
+//!
+//! fn count<T: HasFields>() -> usize {
+//!     T::fields().len()
+//! }
+//!
+//! fn main() {
+//!     assert_eq!(1, count::<File>());
+//!     assert_eq!(1, count::<Wrapper<u8>>());
+//!     assert_eq!(&["id"], File::fields());
+//! }
+//! ```
+//!
+//! ## `vis`
+//! Sets the visibility of the `fields`/`variants` and `field_count`/`variant_count` methods,
+//! which are `pub` by default. An empty string makes them private.
+//! ```rust
+//! mod file {
+//!     use fievar::Fields;
+//!
+//!     #[derive(Fields)]
+//!     #[fievar(vis = "pub(crate)")]
+//!     pub struct File {
+//!         id: String,
+//!     }
+//!
+//!     #[derive(Fields)]
+//!     #[fievar(vis = "")]
+//!     pub struct Secret {
+//!         key: String,
+//!     }
+//!
+//!     pub fn secret_fields() -> &'static [&'static str] {
+//!         Secret::fields()
+//!     }
+//! }
+//!
+//! assert_eq!(&["id"], file::File::fields());
+//! assert_eq!(&["key"], file::secret_fields());
+//! ```
+//!
+//! ```compile_fail
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(vis = "public")]
+//! struct File {
+//!     id: String,
+//! }
+//! ```
+//!
+//! ## `module_const`
+//! Generates a module level constant with the given name, in addition to the method.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(module_const = "FILE_FIELDS")]
+//! struct File {
+//!     id: String,
+//!     name: String,
+//! }
+//!
+//! fn main() {
+//!     assert_eq!(&["id", "name"], crate::FILE_FIELDS);
+//!     assert_eq!(crate::FILE_FIELDS, File::fields());
+//! }
+//! ```
+//!
+//! Without a name, the constant is named after the type, like `FILE_FIELDS` or
+//! `COLOR_VARIANTS`, so several types can have one in the same module.
+//! ```rust
+//! use fievar::{Fields, Variants};
+//!
+//! #[derive(Fields)]
+//! #[fievar(module_const, hash)]
+//! struct File {
+//!     id: String,
+//!     color: Color,
+//! }
+//!
+//! #[derive(Variants)]
+//! #[fievar(module_const, hash)]
+//! enum Color {
+//!     Red,
+//!     Green,
+//! }
+//!
+//! fn main() {
+//!     assert_eq!(&["id", "color"], crate::FILE_FIELDS);
+//!     assert_eq!(&["Red", "Green"], crate::COLOR_VARIANTS);
+//!     assert_ne!(File::FIELDS_HASH, Color::VARIANTS_HASH);
+//! }
+//! ```
+//!
+//! The name must be a valid identifier.
+//! ```compile_fail
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(module_const = "FILE FIELDS")]
+//! struct File {
+//!     id: String,
+//! }
+//! ```
+//!
+//! ## `const_name`
+//! Generates an associated constant with the given name holding the names, which the method
+//! returns. Unlike the method it can be used in const contexts.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(const_name = "FIELDS")]
+//! struct File {
+//!     id: String,
+//!     name: String,
+//! }
+//!
+//! const LEN: usize = File::FIELDS.len();
+//! let sizes = [0u64; LEN];
+//! assert_eq!(2, sizes.len());
+//! assert_eq!(File::FIELDS, File::fields());
+//! ```
+//!
+//! ## `marker`
+//! Generates a unit struct named like `FileFields` or `ColorVariants` that implements
+//! `AsRef<[&'static str]>`, for code that is generic over name lists.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(marker)]
+//! struct File {
+//!     id: String,
+//!     name: String,
+//! }
+//!
+//! fn first(names: &impl AsRef<[&'static str]>) -> &'static str {
+//!     names.as_ref()[0]
+//! }
+//!
+//! let dynamic: &dyn AsRef<[&'static str]> = &FileFields;
+//!
+//! assert_eq!("id", first(&FileFields));
+//! assert_eq!(File::fields(), dynamic.as_ref());
+//! ```
+//!
+//! ## `preview`
+//! Emits a warning listing every field/variant with the name it gets, which helps while
+//! working on a transform. The generated methods are unchanged.
+//!
+//! Proc macros can't emit warnings on stable Rust, so the list is the note of a
+//! `#[deprecated]` item the derive uses. The warning therefore reads as the use of a
+//! deprecated `Preview` struct, is silenced by `#[allow(deprecated)]` and turns into an error
+//! under `#![deny(deprecated)]` or `-D warnings`.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(preview, transform = "c Cc")]
+//! struct File {
+//!     // warning: use of deprecated unit struct `_::Preview`: fievar preview of File:
+//!     // `mime_type` -> `mimeType`
+//!     mime_type: String,
+//! }
+//!
+//! assert_eq!(&["mimeType"], File::fields());
+//! ```
+//!
+//! ```compile_fail
+//! #![deny(deprecated)]
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(preview)]
+//! struct File {
+//!     id: String,
+//! }
+//! ```
+//!
+//! ## `strip_common_prefix`
+//! Removes the leading words shared by all fields/variants before any field attributes
+//! are applied. A field that would become empty keeps its prefix.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(strip_common_prefix)]
+//! struct User {
+//!     user_name: String,
+//!     user_id: String,
+//!     #[fievar(transform = "C")]
+//!     user_email: String,
+//!     user: String,
+//! }
+//!
+//! assert_eq!(&["name", "id", "EMAIL", "user"], User::fields());
+//! ```
+//!
+//! ## `hash`
+//! Generates a `FIELDS_HASH`/`VARIANTS_HASH` constant holding a stable 64-bit FNV-1a hash
+//! of the names in order. It only changes when the names or their order change.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(hash)]
+//! struct A {
+//!     id: String,
+//!     name: String,
+//! }
+//!
+//! #[derive(Fields)]
+//! #[fievar(hash)]
+//! struct B {
+//!     id: String,
+//!     name: String,
+//! }
+//!
+//! #[derive(Fields)]
+//! #[fievar(hash)]
+//! struct C {
+//!     name: String,
+//!     id: String,
+//! }
+//!
+//! assert_eq!(A::FIELDS_HASH, B::FIELDS_HASH);
+//! assert_ne!(A::FIELDS_HASH, C::FIELDS_HASH);
+//! ```
+//!
+//! ## `lines`
+//! Generates a `field_lines`/`variant_lines` method returning the source line each
+//! field/variant is declared on, in the same order as the names. This needs the `lines`
+//! feature and Rust 1.88 to read span locations; older compilers get 0 for every line.
+//! ```rust
+//! # #[cfg(feature = "lines")] {
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(lines)]
+//! struct Config {
+//!     host: String,
+//!
+//!     port: u16,
+//! }
+//!
+//! let lines = Config::field_lines();
+//! assert_eq!(Config::fields().len(), lines.len());
+//! assert!(lines == [0, 0] || lines[1] == lines[0] + 2);
+//! # }
+//! ```
+//!
+//! ## `env_aliases`
+//! Generates an `env_aliases` method returning each name in SCREAMING_SNAKE_CASE, in the
+//! same order as the names, like environment variables.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(env_aliases)]
+//! #[allow(non_snake_case)]
+//! struct Config {
+//!     mimeType: String,
+//!     #[fievar(name = "maxSize")]
+//!     max_size: u64,
+//! }
+//!
+//! assert_eq!(&["mimeType", "maxSize"], Config::fields());
+//! assert_eq!(&["MIME_TYPE", "MAX_SIZE"], Config::env_aliases());
+//! ```
+//!
+//! ## `setters`
+//! Generates a `setter_names` method returning the given prefix followed by each field
+//! ident in snake_case, for generating builders. Field names don't affect these.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(setters = "set_")]
+//! #[allow(non_snake_case)]
+//! struct File {
+//!     #[fievar(name = "ID")]
+//!     id: String,
+//!     mimeType: String,
+//! }
+//!
+//! assert_eq!(&["set_id", "set_mime_type"], File::setter_names());
+//! ```
+//!
+//! ## `pad`, `pad_char`, `align`, `pad_truncate`
+//! Pads every name to `pad` characters after all other attributes are applied. `pad_char`
+//! defaults to a space and `align` to `left`. Longer names are left as they are, unless
+//! `pad_truncate` is set. These can also be set on individual fields, overriding the
+//! container.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(pad = 6)]
+//! struct File {
+//!     id: String,
+//!     #[fievar(align = "right", pad_char = ".")]
+//!     name: String,
+//!     mime_type: String,
+//!     #[fievar(pad_truncate)]
+//!     created_at: String,
+//! }
+//!
+//! assert_eq!(&["id    ", "..name", "mime_type", "create"], File::fields());
+//! ```
+//!
+//! ## `max_len`, `max_len_hash`
+//! Truncates names longer than `max_len` characters. With `max_len_hash`, the end of a
+//! truncated name is replaced with `_` and six hex digits of a hash of the full name, so
+//! names sharing a long prefix stay distinct. Both can also be set on individual fields.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(max_len = 12)]
+//! struct Table {
+//!     id: String,
+//!     café_description: String,
+//!     #[fievar(max_len_hash)]
+//!     long_column_name_one: String,
+//!     #[fievar(max_len_hash)]
+//!     long_column_name_two: String,
+//! }
+//!
+//! let f = Table::fields();
+//! assert_eq!(&["id", "café_descrip"], &f[..2]);
+//! assert!(f[2].starts_with("long_") && f[2].len() == 12);
+//! assert!(f[3].starts_with("long_") && f[3].len() == 12);
+//! assert_ne!(f[2], f[3]);
+//! ```
+//!
+//! ## `index_base`
+//! Tuple struct fields are named by their position. `index_base` sets the number of the
+//! first field, which defaults to 0.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Point(f32, f32);
+//!
+//! #[derive(Fields)]
+//! #[fievar(index_base = 1)]
+//! struct LuaPoint(f32, f32);
+//!
+//! #[derive(Fields)]
+//! #[fievar(index_base = 1)]
+//! struct Named {
+//!     x: f32,
+//! }
+//!
+//! assert_eq!(&["0", "1"], Point::fields());
+//! assert_eq!(&["1", "2"], LuaPoint::fields());
+//! assert_eq!(&["x"], Named::fields());
+//! ```
+//!
+//! Positional names can be overridden or transformed like any other.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Pair(
+//!     #[fievar(name = "left")] u8,
+//!     #[fievar(transform = "C", wrap_first = "_")] u8,
+//!     #[fievar(upper_first = "2")] u8,
+//! );
+//!
+//! assert_eq!(&["left", "_1_", "2"], Pair::fields());
+//! ```
+//!
+//! ## `by_type`
+//! Generates a method for each `name = "Type"` pair, returning only the fields of that type.
+//! The type matches either the whole written type or its last path segment.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! struct Uuid;
+//!
+//! #[derive(Fields)]
+//! #[fievar(by_type(string = "String", id = "Uuid", bytes = "Vec<u8>"))]
+//! struct File {
+//!     id: Uuid,
+//!     parent_id: Uuid,
+//!     name: String,
+//!     mime_type: std::string::String,
+//!     size: u64,
+//!     content: Vec<u8>,
+//! }
+//!
+//! assert_eq!(&["name", "mime_type"], File::string_fields());
+//! assert_eq!(&["id", "parent_id"], File::id_fields());
+//! assert_eq!(&["content"], File::bytes_fields());
+//! ```
+//!
+//! ## `types`
+//! Generates a `field_types` method returning the type of each field as written.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(types)]
+//! struct File<'a> {
+//!     size: u64,
+//!     content: Vec<u8>,
+//!     tags: std::collections::HashMap<String, Vec<&'a str>>,
+//!     name: &'a str,
+//!     parent: Option<&'a mut File<'a>>,
+//!     hash: [u8; 32],
+//!     range: (u64, u64),
+//! }
+//!
+//! let t = &[
+//!     "u64",
+//!     "Vec<u8>",
+//!     "std::collections::HashMap<String, Vec<&'a str>>",
+//!     "&'a str",
+//!     "Option<&'a mut File<'a>>",
+//!     "[u8; 32]",
+//!     "(u64, u64)",
+//! ];
+//! assert_eq!(t, File::field_types());
+//! ```
+//!
+//! ## `joined`
+//! Generates a `FIELDS_JOINED`/`VARIANTS_JOINED` constant holding all names joined with
+//! the given separator.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(joined = ", ")]
+//! struct File {
+//!     id: String,
+//!     name: String,
+//!     #[fievar(name = "mimeType")]
+//!     mime_type: String,
+//! }
+//!
+//! assert_eq!("id, name, mimeType", File::FIELDS_JOINED);
+//! ```
+//!
+//! ## `only`
+//! Lists the fields/variants to keep, by their Rust identifiers, dropping all others.
+//! Naming one that doesn't exist is an error.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(only("id", "mime_type"))]
+//! struct File {
+//!     id: String,
+//!     name: String,
+//!     #[fievar(name = "mimeType")]
+//!     mime_type: String,
+//!     size: u64,
+//! }
+//!
+//! assert_eq!(&["id", "mimeType"], File::fields());
+//! ```
+//!
+//! ```compile_fail
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(only("id", "mimeType"))]
+//! struct File {
+//!     id: String,
+//!     #[fievar(name = "mimeType")]
+//!     mime_type: String,
+//! }
+//! ```
+//!
+//! ## `skip_phantom`
+//! Leaves out fields of type `PhantomData`.
+//! ```rust
+//! use fievar::Fields;
+//! use std::marker::PhantomData;
+//!
+//! #[derive(Fields)]
+//! #[fievar(skip_phantom)]
+//! struct Id<T> {
+//!     id: u64,
+//!     _marker: PhantomData<T>,
+//!     _other: std::marker::PhantomData<fn() -> T>,
+//! }
+//!
+//! assert_eq!(&["id"], Id::<String>::fields());
+//! ```
+//!
+//! ## `pub_only`
+//! Leaves out fields that are not `pub`. Restricted visibilities like `pub(crate)` count
+//! as not public.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(pub_only)]
+//! pub struct File {
+//!     pub id: String,
+//!     pub(crate) name: String,
+//!     mime_type: String,
+//! }
+//!
+//! assert_eq!(&["id"], File::fields());
+//! ```
+//!
+//! ## `iter`
+//! Generates iterator helpers. `fields_where`/`variants_where` return the names for
+//! which the predicate holds, and `fields_iter`/`variants_iter` iterate over the names.
+//! The iterator is `Clone`, `ExactSizeIterator` and `DoubleEndedIterator`.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(iter)]
+//! struct File {
+//!     id: String,
+//!     name: String,
+//!     mime_type: String,
+//!     mime_subtype: String,
+//! }
+//!
+//! assert_eq!(
+//!     vec!["mime_type", "mime_subtype"],
+//!     File::fields_where(|n| n.starts_with("mime_"))
+//! );
+//!
+//! let it = File::fields_iter();
+//! assert_eq!(4, it.len());
+//! assert_eq!(Some("mime_subtype"), it.clone().next_back());
+//! assert_eq!(
+//!     vec!["ID", "NAME"],
+//!     it.filter(|n| !n.contains('_'))
+//!         .map(str::to_uppercase)
+//!         .collect::<Vec<_>>()
+//! );
+//! ```
+//!
+//! ## `owned`
+//! Generates `fields_owned`/`variants_owned` returning the names as owned `String`s.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(owned)]
+//! struct File {
+//!     id: String,
+//!     #[fievar(name = "mimeType")]
+//!     mime_type: String,
+//! }
+//!
+//! let owned: Vec<String> = File::fields_owned();
+//! assert_eq!(File::fields(), &owned[..]);
+//! ```
+//!
+//! ## `array`
+//! Generates a `const fn` `fields_array`/`variants_array` returning the names as an array,
+//! so the number of names is part of the type.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(array)]
+//! struct File {
+//!     id: String,
+//!     #[fievar(skip)]
+//!     cache: Vec<u8>,
+//!     #[fievar(name = "mimeType")]
+//!     mime_type: String,
+//! }
+//!
+//! const NAMES: [&str; 2] = File::fields_array();
+//! assert_eq!(["id", "mimeType"], NAMES);
+//! assert_eq!(File::field_count(), File::fields_array().len());
+//! assert_eq!(File::fields(), &File::fields_array()[..]);
+//! ```
+//!
+//! ## `sort`
+//! With `sort = "alpha"` the names are sorted alphabetically instead of following declaration
+//! order. Everything else generated stays in the same order as the names.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(sort = "alpha", pairs, ordinals)]
+//! struct User {
+//!     name: String,
+//!     #[fievar(name = "age")]
+//!     years: u8,
+//!     email: String,
+//! }
+//!
+//! assert_eq!(&["age", "email", "name"], User::fields());
+//! assert_eq!(
+//!     &[("years", "age"), ("email", "email"), ("name", "name")],
+//!     User::field_name_pairs()
+//! );
+//! assert_eq!(&[("age", 1), ("email", 2), ("name", 0)], User::field_ordinals());
+//! ```
+//!
+//! ## `sorted`
+//! Generates `fields_sorted`/`variants_sorted` returning the names sorted alphabetically,
+//! leaving `fields`/`variants` in declaration order. Equal names keep their order.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(sorted)]
+//! struct User {
+//!     name: String,
+//!     #[fievar(name = "age")]
+//!     years: u8,
+//!     email: String,
+//! }
+//!
+//! assert_eq!(&["name", "age", "email"], User::fields());
+//! assert_eq!(&["age", "email", "name"], User::fields_sorted());
+//!
+//! let mut names = User::fields().to_vec();
+//! names.sort();
+//! assert_eq!(names, User::fields_sorted());
+//! ```
+//!
+//! ## `ordinals`
+//! Generates a `field_ordinals`/`variant_ordinals` method returning `(name, ordinal)` tuples,
+//! like protobuf field numbers. The ordinal is the declaration index unless a field/variant
+//! sets its own with `ordinal`. Ordinals must be unique.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(ordinals)]
+//! struct Msg {
+//!     id: u64,
+//!     #[fievar(ordinal = 5)]
+//!     body: String,
+//!     sent: bool,
+//! }
+//!
+//! assert_eq!(&[("id", 0), ("body", 5), ("sent", 2)], Msg::field_ordinals());
+//! ```
+//!
+//! ```compile_fail
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(ordinals)]
+//! struct Msg {
+//!     id: u64,
+//!     #[fievar(ordinal = 0)]
+//!     body: String,
+//! }
+//! ```
+//!
+//! ## `pairs`
+//! Generates a `field_name_pairs`/`variant_name_pairs` method returning `(ident, name)`
+//! tuples in the same order as the names, so maps can be built in either direction. Raw
+//! identifiers have their `r#` removed.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(pairs)]
+//! struct File {
+//!     #[fievar(name = "kind")]
+//!     r#type: String,
+//!     #[fievar(transform = "c Cc")]
+//!     mime_type: String,
+//! }
+//!
+//! assert_eq!(
+//!     &[("type", "kind"), ("mime_type", "mimeType")],
+//!     File::field_name_pairs()
+//! );
+//! ```
+//!
+//! Skipped fields are left out and the pairs follow any reordering of the names.
+//! ```rust
+//! use fievar::Variants;
+//!
+//! #[derive(Variants)]
+//! #[fievar(pairs, rename_all = "kebab-case")]
+//! enum Event {
+//!     #[fievar(after = "UserLeft")]
+//!     UserJoined,
+//!     #[fievar(skip)]
+//!     Internal,
+//!     #[fievar(name = "bye")]
+//!     UserLeft,
+//! }
+//!
+//! assert_eq!(&["bye", "user-joined"], Event::variants());
+//! assert_eq!(
+//!     &[("UserLeft", "bye"), ("UserJoined", "user-joined")],
+//!     Event::variant_name_pairs()
+//! );
+//! ```
+//!
+//! ## `renamed`
+//! Generates a `renamed_fields`/`renamed_variants` method returning the idents whose name
+//! differs from the ident itself, for auditing which names are not identity mappings.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(renamed)]
+//! struct File {
+//!     id: String,
+//!     #[fievar(name = "mimeType")]
+//!     mime_type: String,
+//!     #[fievar(transform = "c")]
+//!     size: u64,
+//!     #[fievar(transform = "C")]
+//!     hash: String,
+//! }
+//!
+//! assert_eq!(&["mime_type", "hash"], File::renamed_fields());
+//! ```
+//!
+//! ## `bits`
+//! Treats each name as a bit flag at its position. Generates a `bit_of` method returning
+//! the bit position of a name, and a `FIELD_MASK`/`VARIANT_MASK` constant with a bit set for
+//! every name. At most 64 names are supported.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(bits)]
+//! struct Perms {
+//!     read: bool,
+//!     write: bool,
+//!     exec: bool,
+//! }
+//!
+//! assert_eq!(Some(0), Perms::bit_of("read"));
+//! assert_eq!(Some(2), Perms::bit_of("exec"));
+//! assert_eq!(None, Perms::bit_of("delete"));
+//! assert_eq!(0b111, Perms::FIELD_MASK);
+//! ```
+//!
+//! ## `pattern`
+//! Checks every name against a pattern and fails to compile when one doesn't match. Patterns
+//! are a small subset of regular expressions: literal characters, `.`, classes like `[a-z_]`
+//! or `[^0-9]`, `\\` escapes, the `?`, `*` and `+` quantifiers, and the `^` and `$` anchors.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(pattern = "^[a-z][a-z0-9_]*$")]
+//! struct Config {
+//!     max_size: u64,
+//!     #[fievar(name = "retry2")]
+//!     retry: u8,
+//! }
+//!
+//! assert_eq!(&["max_size", "retry2"], Config::fields());
+//! ```
+//!
+//! ```compile_fail
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(pattern = "^[a-z][a-z0-9_]*$")]
+//! struct Config {
+//!     #[fievar(transform = "c Cc")]
+//!     max_size: u64,
+//! }
+//! ```
+//!
+//! ## `deny_duplicates`
+//! Makes it an error for two fields/variants to end up with the same name, whether it
+//! was given with `name` or produced by a transform. The error names both of them.
+//! ```compile_fail
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(deny_duplicates)]
+//! struct File {
+//!     #[fievar(name = "id")]
+//!     file_id: String,
+//!     #[fievar(name = "id")]
+//!     parent_id: String,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(deny_duplicates)]
+//! struct File {
+//!     #[fievar(name = "mimeType")]
+//!     mime: String,
+//!     #[fievar(transform = "c Cc")]
+//!     mime_type: String,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(deny_duplicates, transform = "c Cc")]
+//! struct User {
+//!     #[fievar(name = "userId")]
+//!     id: String,
+//!     user_id: String,
+//! }
+//! ```
+//!
+//! ## `lookup`
+//! Generates `ident_for_name` and `name_for_ident` to map between generated names and
+//! Rust identifiers.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(lookup)]
+//! struct File {
+//!     id: String,
+//!     #[fievar(name = "mimeType")]
+//!     mime_type: String,
+//! }
+//!
+//! assert_eq!(Some("mime_type"), File::ident_for_name("mimeType"));
+//! assert_eq!(Some("id"), File::ident_for_name("id"));
+//! assert_eq!(None, File::ident_for_name("mime_type"));
+//! assert_eq!(Some("mimeType"), File::name_for_ident("mime_type"));
+//! ```
+//!
+//! ## `index`
+//! Generates a `field_index`/`variant_index` method returning the position of a name in the
+//! generated list.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(index)]
+//! struct File {
+//!     id: String,
+//!     #[fievar(skip)]
+//!     cache: Vec<u8>,
+//!     #[fievar(name = "mimeType")]
+//!     mime_type: String,
+//! }
+//!
+//! assert_eq!(Some(0), File::field_index("id"));
+//! assert_eq!(Some(1), File::field_index("mimeType"));
+//! assert_eq!(None, File::field_index("mime_type"));
+//! assert_eq!(None, File::field_index("cache"));
+//! ```
+//!
+//! ## `contains`
+//! Generates a `has_field`/`has_variant` method checking whether a name is in the generated
+//! list.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(contains)]
+//! struct File {
+//!     id: String,
+//!     #[fievar(name = "mimeType")]
+//!     mime_type: String,
+//! }
+//!
+//! assert!(File::has_field("id"));
+//! assert!(File::has_field("mimeType"));
+//! assert!(!File::has_field("mime_type"));
+//! assert!(!File::has_field("size"));
+//! ```
+//!
+//! ## `set_fields`
+//! For structs whose fields are all `Option`s, generates a `set_fields` method returning
+//! the names of the fields that are `Some`.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(set_fields)]
+//! struct FileUpdate {
+//!     name: Option<String>,
+//!     #[fievar(name = "mimeType")]
+//!     mime_type: Option<String>,
+//!     size: std::option::Option<u64>,
+//! }
+//!
+//! let u = FileUpdate {
+//!     name: None,
+//!     mime_type: Some("text/plain".into()),
+//!     size: Some(4),
+//! };
+//!
+//! assert_eq!(vec!["mimeType", "size"], u.set_fields());
+//! ```
+//!
+//! ```compile_fail
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(set_fields)]
+//! struct FileUpdate {
+//!     name: Option<String>,
+//!     size: u64,
+//! }
+//! ```
+//!
+//! ## `get`
+//! Generates a `get` method returning a reference to the field with the given name. Every
+//! field must have the same type.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(get)]
+//! struct Row {
+//!     a: i32,
+//!     #[fievar(name = "B")]
+//!     b: i32,
+//! }
+//!
+//! let row = Row { a: 1, b: 2 };
+//! assert_eq!(Some(&1), row.get("a"));
+//! assert_eq!(Some(&2), row.get("B"));
+//! assert_eq!(None, row.get("b"));
+//! ```
+//!
+//! ```compile_fail
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(get)]
+//! struct Row {
+//!     a: i32,
+//!     b: String,
+//! }
+//! ```
+//!
+//! ## `validate`
+//! Generates a `validate` method that calls `self.validate_field(name)` for every name
+//! and returns the names it returned `false` for. `validate_field` has to be provided,
+//! either as an inherent method or through a trait in scope.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(validate)]
+//! struct File {
+//!     #[fievar(name = "fileName")]
+//!     name: String,
+//!     size: u64,
+//! }
+//!
+//! impl File {
+//!     fn validate_field(&self, name: &str) -> bool {
+//!         match name {
+//!             "fileName" => !self.name.is_empty(),
+//!             "size" => self.size > 0,
+//!             _ => true,
+//!         }
+//!     }
+//! }
+//!
+//! let ok = File { name: "a.txt".into(), size: 1 };
+//! let bad = File { name: "".into(), size: 0 };
+//!
+//! assert_eq!(Ok(()), ok.validate());
+//! assert_eq!(Err(vec!["fileName", "size"]), bad.validate());
+//! ```
+//!
+//! ## `cstr`
+//! Generates `fields_cstr`/`variants_cstr` returning the names as nul-terminated
+//! `&'static CStr`s. This needs the `cstr` feature and Rust 1.77 or newer.
+//! ```rust
+//! # #[cfg(feature = "cstr")] {
+//! use fievar::Fields;
+//! use std::ffi::CStr;
+//!
+//! #[derive(Fields)]
+//! #[fievar(cstr)]
+//! struct File {
+//!     id: String,
+//!     #[fievar(name = "mimeType")]
+//!     mime_type: String,
+//! }
+//!
+//! let names: &[&CStr] = File::fields_cstr();
+//! assert_eq!(b"mimeType\0", names[1].to_bytes_with_nul());
+//! assert_eq!(vec!["id", "mimeType"], names.iter().map(|c| c.to_str().unwrap()).collect::<Vec<_>>());
+//! # }
+//! ```
+//!
+// Without the feature this example would fail on `cstr` itself, so it is only part of the
+// docs when the nul check can actually run.
+#![cfg_attr(
+    feature = "cstr",
+    doc = r#"Names with a nul character are rejected.
+```compile_fail
+use fievar::Fields;
+
+#[derive(Fields)]
+#[fievar(cstr)]
+struct File {
+    #[fievar(name = "mime\0type")]
+    mime_type: String,
+}
+```
+"#
+)]
+//!
+//! ## `phf`
+//! Generates `field_set`/`variant_set` returning the names as a perfect-hash
+//! [`phf::Set`](https://docs.rs/phf), for fast membership checks. This needs the `phf`
+//! feature, and the deriving crate must depend on `phf` with its `macros` feature.
+//! ```rust,ignore
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(phf)]
+//! struct File {
+//!     id: String,
+//!     #[fievar(name = "mimeType")]
+//!     mime_type: String,
+//! }
+//!
+//! assert!(File::field_set().contains("mimeType"));
+//! assert!(!File::field_set().contains("mime_type"));
+//! assert_eq!(2, File::field_set().len());
+//! ```
+//!
+//! ## `deascii`
+//! Replaces accented Latin letters in the final names with their base letter, for systems
+//! that only handle ASCII keys. This needs the `latin_fold` feature.
+//!
+//! This is a limited fold, not Unicode decomposition. Only the letters of the Latin-1
+//! Supplement and Latin Extended-A blocks are replaced, and combining marks are dropped.
+//! Other accented letters such as `ș`, `ǎ`, `ạ` or `ế` are kept as they are, as are letters
+//! without a base letter such as `ß` or `ø`.
+//! ```rust
+//! # #[cfg(feature = "latin_fold")] {
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(deascii)]
+//! struct Menu {
+//!     café_name: String,
+//!     #[fievar(name = "Größe")]
+//!     size: u32,
+//!     #[fievar(transform = "C|_")]
+//!     crème_brûlée: f32,
+//! }
+//!
+//! assert_eq!(&["cafe_name", "Große", "CREME_BRULEE"], Menu::fields());
+//! # }
+//! ```
+//!
+//! ```rust
+//! # #[cfg(feature = "latin_fold")] {
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(deascii)]
+//! struct City {
+//!     #[fievar(name = "Timișoara")]
+//!     timisoara: String,
+//!     #[fievar(name = "Hà Nội")]
+//!     hanoi: String,
+//!     #[fievar(name = "Cafe\u{301}")]
+//!     cafe: String,
+//! }
+//!
+//! assert_eq!(&["Timișoara", "Ha Nội", "Cafe"], City::fields());
+//! # }
+//! ```
+//!
+//! ## `heck`
+//! Converts every name the same way the [heck](https://docs.rs/heck) crate would, which
+//! splits words differently from transforms. Fields with their own `name` or `transform`
+//! are left alone. Accepts `snake`, `kebab`, `shouty_snake`, `shouty_kebab`,
+//! `upper_camel`, `lower_camel`, `title` and `train`.
+//! ```rust
+//! use fievar::Variants;
+//!
+//! #[derive(Variants)]
+//! #[fievar(heck = "snake")]
+//! enum Snake { XMLHttpRequest, IOError, ABC123Def, #[fievar(name = "v2")] V2 }
+//!
+//! #[derive(Variants)]
+//! #[fievar(heck = "upper_camel")]
+//! enum UpperCamel { XMLHttpRequest, IOError, ABC123Def }
+//!
+//! #[derive(Variants)]
+//! #[fievar(heck = "lower_camel")]
+//! enum LowerCamel { XMLHttpRequest, IOError, ABC123Def }
+//!
+//! #[derive(Variants)]
+//! #[fievar(heck = "kebab")]
+//! enum Kebab { XMLHttpRequest, IOError, ABC123Def }
+//!
+//! #[derive(Variants)]
+//! #[fievar(heck = "shouty_snake")]
+//! enum ShoutySnake { XMLHttpRequest, IOError, ABC123Def }
+//!
+//! #[derive(Variants)]
+//! #[fievar(heck = "shouty_kebab")]
+//! enum ShoutyKebab { XMLHttpRequest, IOError, ABC123Def }
+//!
+//! #[derive(Variants)]
+//! #[fievar(heck = "title")]
+//! enum Title { XMLHttpRequest, IOError, ABC123Def }
+//!
+//! #[derive(Variants)]
+//! #[fievar(heck = "train")]
+//! enum Train { XMLHttpRequest, IOError, ABC123Def }
+//!
+//! assert_eq!(&["xml_http_request", "io_error", "abc123_def", "v2"], Snake::variants());
+//! assert_eq!(&["XmlHttpRequest", "IoError", "Abc123Def"], UpperCamel::variants());
+//! assert_eq!(&["xmlHttpRequest", "ioError", "abc123Def"], LowerCamel::variants());
+//! assert_eq!(&["xml-http-request", "io-error", "abc123-def"], Kebab::variants());
+//! assert_eq!(&["XML_HTTP_REQUEST", "IO_ERROR", "ABC123_DEF"], ShoutySnake::variants());
+//! assert_eq!(&["XML-HTTP-REQUEST", "IO-ERROR", "ABC123-DEF"], ShoutyKebab::variants());
+//! assert_eq!(&["Xml Http Request", "Io Error", "Abc123 Def"], Title::variants());
+//! assert_eq!(&["Xml-Http-Request", "Io-Error", "Abc123-Def"], Train::variants());
+//! ```
+//!
+//! ## `keep_words`
+//! Lists words that `heck` and transforms emit exactly as written in the identifier. A kept
+//! word is always a word of its own and its case is never changed.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(heck = "snake", keep_words("ID", "URL"))]
+//! #[allow(non_snake_case)]
+//! struct User {
+//!     userIDCard: u32,
+//!     avatarURL: String,
+//!     IdNumber: u32,
+//! }
+//!
+//! assert_eq!(&["user_ID_card", "avatar_URL", "id_number"], User::fields());
+//! ```
+//!
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(transform = "c|_", keep_words("ID"))]
+//! #[allow(non_snake_case)]
+//! struct User {
+//!     userIDCard: u32,
+//!     #[fievar(transform = "C Cc")]
+//!     IDNumber: u32,
+//!     #[fievar(case = "kebab")]
+//!     lastID: u32,
+//! }
+//!
+//! assert_eq!(&["user_ID_card", "IDNumber", "last-ID"], User::fields());
+//! ```
+//!
+//! ## `keep_leading_underscore`
+//! Keeps a single leading underscore through a `transform`, which otherwise drops the
+//! underscores before the first word.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(transform = "c|_", keep_leading_underscore)]
+//! struct Snake {
+//!     _internal: u8,
+//!     __reserved_id: u8,
+//!     public: u8,
+//! }
+//!
+//! #[derive(Fields)]
+//! #[fievar(transform = "Cc", keep_leading_underscore)]
+//! struct Pascal {
+//!     _internal: u8,
+//! }
+//!
+//! assert_eq!(&["_internal", "_reserved_id", "public"], Snake::fields());
+//! assert_eq!(&["_Internal"], Pascal::fields());
+//! ```
+//!
+//! ## `acronyms`
+//! Lists words that a `transform` always emits fully uppercase, whatever the transform would
+//! do to them. Words are matched case-insensitively after splitting.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(transform = "Cc", acronyms("ID", "HTTP", "URL"))]
+//! struct Request {
+//!     user_id: u32,
+//!     http_url: String,
+//!     body: String,
+//! }
+//!
+//! assert_eq!(&["UserID", "HTTPURL", "Body"], Request::fields());
+//! ```
+//!
+//! ## `prefix`, `suffix`
+//! Adds text before or after every name, after any transform or rename. These can also be
+//! set on a field/variant, which then takes precedence over the container.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(prefix = "x_")]
+//! struct Query {
+//!     #[fievar(suffix = "_v2")]
+//!     user: String,
+//!     #[fievar(transform = "C", prefix = "")]
+//!     limit: u32,
+//!     #[fievar(name = "sortBy")]
+//!     sort: String,
+//! }
+//!
+//! assert_eq!(&["x_user_v2", "LIMIT", "x_sortBy"], Query::fields());
+//! ```
+//!
+//! ## `title_case`
+//! Converts every name to Title Case for human readable labels. Small words like `of`, `the`
+//! and `and` stay lowercase unless they are the first or last word. The small words can be
+//! given instead, like `title_case("of", "per")`. Fields with their own `name` or
+//! `transform` are left alone.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(title_case)]
+//! struct Person {
+//!     date_of_birth: String,
+//!     place_of: String,
+//! }
+//!
+//! #[derive(Fields)]
+//! #[fievar(title_case("per"))]
+//! struct Speed {
+//!     miles_per_hour: f32,
+//!     time_of_day: u32,
+//! }
+//!
+//! assert_eq!(&["Date of Birth", "Place Of"], Person::fields());
+//! assert_eq!(&["Miles per Hour", "Time Of Day"], Speed::fields());
+//! ```
+//!
+//! ## `rename_all`
+//! Same as `heck`, but takes the case names serde uses: `snake_case`, `kebab-case`,
+//! `SCREAMING_SNAKE_CASE`, `SCREAMING-KEBAB-CASE`, `PascalCase` and `camelCase`.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(rename_all = "camelCase")]
+//! struct User {
+//!     user_id: u32,
+//!     display_name: String,
+//! }
+//!
+//! assert_eq!(&["userId", "displayName"], User::fields());
+//! ```
+//!
+//! `PascalCase` also starts a new word after digits, which suits type names.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(rename_all = "PascalCase")]
+//! struct Types {
+//!     parse_v2_config: String,
+//!     parse_v2config: String,
+//!     http_2_server: String,
+//! }
+//!
+//! assert_eq!(&["ParseV2Config", "ParseV2Config", "Http2Server"], Types::fields());
+//! ```
+//!
+//! `jackson_camel` is camelCase the way Jackson does it, keeping acronyms uppercase.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(rename_all = "jackson_camel")]
+//! #[allow(non_snake_case)]
+//! struct Response {
+//!     HTTPStatusCode: u16,
+//!     userHTTPStatus: u16,
+//!     user_id: u32,
+//! }
+//!
+//! assert_eq!(&["HTTPStatusCode", "userHTTPStatus", "userId"], Response::fields());
+//! ```
+//!
+//! ## `qualified_variants`
+//! Prefixes every variant name with the enum name and a separator, which defaults to `_`.
+//! The enum name is converted by `heck`/`rename_all` the same way as the variants, while
+//! per-variant `name` and `transform` only affect the variant part.
+//! ```rust
+//! use fievar::Variants;
+//!
+//! #[derive(Variants)]
+//! #[fievar(qualified_variants, rename_all = "SCREAMING_SNAKE_CASE")]
+//! enum Color {
+//!     LightRed,
+//!     #[fievar(name = "DARK")]
+//!     DarkGray,
+//! }
+//!
+//! #[derive(Variants)]
+//! #[fievar(qualified_variants = "::")]
+//! enum Shape {
+//!     Circle,
+//! }
+//!
+//! assert_eq!(&["COLOR_LIGHT_RED", "COLOR_DARK"], Color::variants());
+//! assert_eq!(&["Shape::Circle"], Shape::variants());
+//! ```
+//!
+//! ## `variant_fields`
+//! Generates a `fields_by_variant` method returning each variant name together with the
+//! names of the fields it carries, as written. Tuple variant fields are named by index.
+//! ```rust
+//! use fievar::Variants;
+//!
+//! #[derive(Variants)]
+//! #[fievar(variant_fields, transform = "c|_")]
+//! enum Shape {
+//!     Point,
+//!     Circle(f32),
+//!     Rect { width: f32, r#type: u8 },
+//! }
+//!
+//! let v: &[(&str, &[&str])] = &[
+//!     ("point", &[]),
+//!     ("circle", &["0"]),
+//!     ("rect", &["width", "type"]),
+//! ];
+//! assert_eq!(v, Shape::fields_by_variant());
+//! assert_eq!(&["point", "circle", "rect"], Shape::variants());
+//! ```
+//!
+//! ## `ident_safe`
+//! Prefixes names that start with a digit, so they can be used as identifiers. The prefix
+//! defaults to `_`. This applies to explicit `name`s as well as transformed ones.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(ident_safe)]
+//! struct Auth {
+//!     #[fievar(name = "2fa")]
+//!     two_factor: bool,
+//!     password: String,
+//! }
+//!
+//! #[derive(Fields)]
+//! #[fievar(ident_safe = "n")]
+//! struct Codes(u8, u8);
+//!
+//! assert_eq!(&["_2fa", "password"], Auth::fields());
+//! assert_eq!(&["n0", "n1"], Codes::fields());
+//! ```
+//!
+//! ## `graphql_safe`
+//! GraphQL reserves names starting with `__`, so leading underscores are reduced to one.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(graphql_safe)]
+//! struct Node {
+//!     __typename_override: String,
+//!     #[fievar(name = "___id")]
+//!     id: u32,
+//!     _private: bool,
+//! }
+//!
+//! assert_eq!(&["_typename_override", "_id", "_private"], Node::fields());
+//! ```
+//!
+//! # Field attributes
+//! These are applied to individual fields or variants.
+//!
+//! ## `after`
+//! Places a field/variant after another one, regardless of declaration order.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Config {
+//!     #[fievar(after = "port")]
+//!     url: String,
+//!     #[fievar(after = "host")]
+//!     port: u16,
+//!     host: String,
+//! }
+//!
+//! assert_eq!(&["host", "port", "url"], Config::fields());
+//! ```
+//!
+//! ```compile_fail
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Config {
+//!     #[fievar(after = "port")]
+//!     host: String,
+//!     #[fievar(after = "host")]
+//!     port: u16,
+//! }
+//! ```
+//!
+//! ## `skip`
+//! Leaves a field/variant out entirely. Other fields/variants can still be placed `after` it.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct User {
+//!     id: u32,
+//!     #[fievar(skip, name = "pw")]
+//!     password: String,
+//!     #[fievar(after = "password")]
+//!     email: String,
+//!     name: String,
+//! }
+//!
+//! assert_eq!(&["id", "email", "name"], User::fields());
+//! ```
+//!
+//! ## `computed`
+//! Marks a field/variant as computed rather than stored. It is left out of the names and
+//! everything generated from them, and listed by a `computed_fields`/`computed_variants`
+//! method instead.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct Rect {
+//!     width: u32,
+//!     height: u32,
+//!     #[fievar(computed, transform = "C")]
+//!     area: u32,
+//! }
+//!
+//! assert_eq!(&["width", "height"], Rect::fields());
+//! assert_eq!(&["AREA"], Rect::computed_fields());
+//! ```
+//!
+//! ## `when`
+//! Only lists a field/variant when the given cargo feature of the deriving crate is
+//! enabled. This applies to the names returned by the method as well as every other list,
+//! lookup and constant generated from them.
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! struct File {
+//!     id: String,
+//!     #[fievar(when = "cstr")]
+//!     raw_name: Vec<u8>,
+//! }
+//!
+//! // Doc tests are built with the features of fievar itself.
+//! #[cfg(feature = "cstr")]
+//! assert_eq!(&["id", "raw_name"], File::fields());
+//! #[cfg(not(feature = "cstr"))]
+//! assert_eq!(&["id"], File::fields());
+//! ```
+//!
+//! ```rust
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(owned, contains, pairs, lookup)]
+//! struct File {
+//!     id: String,
+//!     #[fievar(when = "cstr", name = "rawName")]
+//!     raw_name: Vec<u8>,
+//!     #[fievar(computed, when = "cstr")]
+//!     raw_len: usize,
+//!     #[fievar(dynamic_prefix = "raw_", when = "cstr")]
+//!     raw_extra: std::collections::HashMap<String, String>,
+//! }
+//!
+//! let on = cfg!(feature = "cstr");
+//! assert_eq!(on, File::computed_fields() == ["raw_len"]);
+//! assert_eq!(on, File::dynamic_prefixes() == ["raw_"]);
+//! assert_eq!(File::fields().len(), File::fields_owned().len());
+//! assert_eq!(File::fields().len(), File::field_name_pairs().len());
+//! assert_eq!(on, File::has_field("rawName"));
+//! assert_eq!(on, File::name_for_ident("raw_name").is_some());
+//! ```
+//!
+//! `joined`, `hash`, `index`, `bits` and `phf` are worked out from every name when the
+//! macro expands, before the features are known, so they can't be combined with `when`.
+//! ```compile_fail
+//! use fievar::Fields;
+//!
+//! #[derive(Fields)]
+//! #[fievar(joined = ", ")]
+//! struct File {
+//!     id: String,
+//!     #[fievar(when = "cstr")]
+//!     raw_name: Vec<u8>,
+//! }
+//! ```
+//!
+//! ## `dynamic_prefix`
+//! Marks a field that holds an open-ended set of keys sharing a prefix, such as a flattened
+//! map. The field is named `{prefix}*` and its prefix is listed by a `dynamic_prefixes` method.
+//! ```rust
+//! use fievar::Fields;
+//! use std::collections::HashMap;
+//!
+//! #[derive(Fields)]
+//! struct Doc {
+//!     title: String,
+//!     #[fievar(dynamic_prefix = "meta_")]
+//!     extra: HashMap<String, String>,
+//! }
+//!
+//! assert_eq!(&["title", "meta_*"], Doc::fields());
+//! assert_eq!(&["meta_"], Doc::dynamic_prefixes());
+//! ```
+
+mod types;
+mod utils;
+
+use proc_macro::TokenStream;
+use proc_macro_error::{abort, abort_call_site, proc_macro_error};
+use quote::quote;
+use syn::{
+    ext::IdentExt, parse_macro_input, spanned::Spanned, Attribute, Data, DataEnum, DataStruct,
+    DataUnion, DeriveInput, Fields, FieldsNamed, FieldsUnnamed, Ident, Index, Lit, LitStr, Member,
+    Meta, MetaList, MetaNameValue, NestedMeta, Path, Type, TypePath, Visibility,
+};
+use types::{
+    Container, Expr, ExprOpts, ExprParser, FievarParser, HeckCase, Item, NumAlign, Pad, PadAlign,
+    Pattern, RenameRule, Tr, TrChars,
+};
+
+const FIEVAR: &str = "fievar";
+const FIELD_KEYS: &[&str] = &[
+    "case",
+    "name",
+    "transform",
+    "upper_first",
+    "after",
+    "wrap_first",
+    "wrap_last",
+    "dynamic_prefix",
+    "when",
+    "ordinal",
+    "prefix",
+    "suffix",
+    "pad",
+    "pad_char",
+    "align",
+    "max_len",
+];
+const CONTAINER_KEYS: &[&str] = &[
+    "fn",
+    "vis",
+    "impl_trait",
+    "module_const",
+    "const_name",
+    "joined",
+    "transform",
+    "index_base",
+    "qualified_variants",
+    "ident_safe",
+    "setters",
+    "prefix",
+    "suffix",
+    "sort",
+    "pattern",
+    "rename_all",
+    "heck",
+    "pad",
+    "pad_char",
+    "align",
+    "max_len",
+];
+
+/// Container keys that each rename every field/variant.
+const RENAMING_KEYS: &[&str] = &["transform", "heck", "rename_all", "title_case"];
+
+macro_rules! gen_impl {
+    ($strenm:expr, $generics:expr, $container:expr, $items:expr, $fievars:expr, $fn_name:ident) => {{
+        let fievars = $fievars;
+        let strenm = $strenm;
+        let generics = $generics;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let container = $container;
+        let items = $items;
+        let method = container
+            .fn_name
+            .clone()
+            .unwrap_or_else(|| quote::format_ident!("{}", stringify!($fn_name)));
+        let vis = container.vis.clone().map_or_else(|| quote!(pub), |v| quote!(#v));
+        // The `cfg` of each item with a `when`, put in front of everything generated for it.
+        let gates = items
+            .iter()
+            .map(|i| when(i).map(|f| quote!(#[cfg(feature = #f)])))
+            .collect::<Vec<_>>();
+        let entries = gates
+            .iter()
+            .zip(&fievars)
+            .map(|(g, n)| quote!(#g #n))
+            .collect::<Vec<_>>();
+        if let Some(f) = items.iter().find_map(when) {
+            let computed = [
+                ("joined", container.joined.is_some()),
+                ("hash", container.hash),
+                ("index", container.index),
+                ("bits", container.bits),
+                ("phf", container.phf),
+            ];
+            if let Some((k, _)) = computed.iter().find(|(_, on)| *on) {
+                abort!(
+                    f,
+                    "`when` can't be combined with `{}`", k;
+                    note = "`{}` is computed from every name before features are known", k
+                );
+            }
+        }
+        let module_const = container.module_const.clone().or_else(|| {
+            container.module_const_auto.then(|| {
+                let c = format!("{}_{}", strenm, stringify!($fn_name));
+                quote::format_ident!("{}", utils::heck(&c, HeckCase::ShoutySnake, &[]))
+            })
+        });
+        let module_const = module_const.map(|c| {
+            quote! (
+                pub const #c: &[&str] = &[#(#entries),*];
+            )
+        });
+        let marker = container.marker.then(|| {
+            let m = quote::format_ident!(
+                "{}{}",
+                strenm,
+                utils::heck(stringify!($fn_name), HeckCase::UpperCamel, &[])
+            );
+            quote! (
+                pub struct #m;
+
+                impl ::core::convert::AsRef<[&'static str]> for #m {
+                    fn as_ref(&self) -> &[&'static str] {
+                        &[#(#entries),*]
+                    }
+                }
+            )
+        });
+        let hash = container.hash.then(|| {
+            let c = quote::format_ident!("{}_HASH", stringify!($fn_name).to_uppercase());
+            let h = utils::fnv(&fievars);
+            quote! (
+                pub const #c: u64 = #h;
+            )
+        });
+
+        let joined = container.joined.as_ref().map(|sep| {
+            let c = quote::format_ident!("{}_JOINED", stringify!($fn_name).to_uppercase());
+            let j = fievars.join(&sep.value());
+            quote! (
+                pub const #c: &'static str = #j;
+            )
+        });
+        let iter = container.iter.then(|| {
+            let w = quote::format_ident!("{}_where", stringify!($fn_name));
+            let i = quote::format_ident!("{}_iter", stringify!($fn_name));
+            quote! (
+                pub fn #w(pred: impl Fn(&str) -> bool) -> ::std::vec::Vec<&'static str> {
+                    Self::#method().iter().copied().filter(|n| pred(n)).collect()
+                }
+
+                pub fn #i() -> impl ::core::iter::Iterator<Item = &'static str>
+                       + ::core::iter::ExactSizeIterator
+                       + ::core::iter::DoubleEndedIterator
+                       + ::core::clone::Clone {
+                    Self::#method().iter().copied()
+                }
+            )
+        });
+        let owned = container.owned.then(|| {
+            let o = quote::format_ident!("{}_owned", stringify!($fn_name));
+            quote! (
+                pub fn #o() -> ::std::vec::Vec<::std::string::String> {
+                    Self::#method().iter().copied().map(::std::string::String::from).collect()
+                }
+            )
+        });
+        let array = container.array.then(|| {
+            let a = quote::format_ident!("{}_array", stringify!($fn_name));
+            quote! (
+                pub const fn #a() -> [&'static str; <[&str]>::len(&[#(#entries),*])] {
+                    [#(#entries),*]
+                }
+            )
+        });
+        // `emit_warning!` is dropped on stable, but using a deprecated item warns everywhere.
+        // The user's span keeps the lint from being silenced as coming from a macro.
+        let preview = container.preview.then(|| {
+            let list = items
+                .iter()
+                .zip(&fievars)
+                .map(|(i, n)| format!("`{}` -> `{}`", member_name(&i.member), n))
+                .collect::<Vec<_>>();
+            let note = format!("fievar preview of {}: {}", strenm, list.join(", "));
+            quote::quote_spanned! (strenm.span()=>
+                const _: () = {
+                    #[deprecated(note = #note)]
+                    struct Preview;
+                    let _ = Preview;
+                };
+            )
+        });
+        let sorted = container.sorted.then(|| {
+            let f = quote::format_ident!("{}_sorted", stringify!($fn_name));
+            let mut pairs = fievars.iter().zip(&entries).collect::<Vec<_>>();
+            pairs.sort_by(|(a, _), (b, _)| a.cmp(b));
+            let e = pairs.into_iter().map(|(_, e)| e);
+            quote! (
+                pub fn #f() -> &'static [&'static str] {
+                    &[#(#e),*]
+                }
+            )
+        });
+        let lookup = container.lookup.then(|| {
+            let idents = items
+                .iter()
+                .map(|i| member_name(&i.member))
+                .collect::<Vec<_>>();
+            quote! (
+                #[allow(unreachable_patterns)]
+                pub fn ident_for_name(name: &str) -> ::core::option::Option<&'static str> {
+                    match name {
+                        #(#gates #fievars => ::core::option::Option::Some(#idents),)*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+
+                #[allow(unreachable_patterns)]
+                pub fn name_for_ident(ident: &str) -> ::core::option::Option<&'static str> {
+                    match ident {
+                        #(#gates #idents => ::core::option::Option::Some(#fievars),)*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            )
+        });
+        let index = container.index.then(|| {
+            let n = stringify!($fn_name);
+            let f = quote::format_ident!("{}_index", &n[..n.len() - 1]);
+            let i = 0..fievars.len();
+            quote! (
+                #[allow(unreachable_patterns)]
+                pub fn #f(name: &str) -> ::core::option::Option<usize> {
+                    match name {
+                        #(#fievars => ::core::option::Option::Some(#i),)*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            )
+        });
+        let contains = container.contains.then(|| {
+            let n = stringify!($fn_name);
+            let f = quote::format_ident!("has_{}", &n[..n.len() - 1]);
+            quote! (
+                #[allow(unreachable_patterns)]
+                pub fn #f(name: &str) -> bool {
+                    match name {
+                        #(#gates #fievars => true,)*
+                        _ => false,
+                    }
+                }
+            )
+        });
+        let phf = container.phf.then(|| {
+            let n = stringify!($fn_name);
+            let f = quote::format_ident!("{}_set", &n[..n.len() - 1]);
+            quote! (
+                pub fn #f() -> &'static ::phf::Set<&'static str> {
+                    static SET: ::phf::Set<&'static str> = ::phf::phf_set! { #(#fievars),* };
+                    &SET
+                }
+            )
+        });
+        let cstr = container.cstr.then(|| {
+            let f = quote::format_ident!("{}_cstr", stringify!($fn_name));
+            let lits = items.iter().zip(&fievars).map(|(i, n)| cstr_lit(&i.member, n));
+            quote! (
+                pub fn #f() -> &'static [&'static ::core::ffi::CStr] {
+                    &[#(#gates #lits),*]
+                }
+            )
+        });
+        let lines = container.lines.then(|| {
+            let n = stringify!($fn_name);
+            let f = quote::format_ident!("{}_lines", &n[..n.len() - 1]);
+            let l = items.iter().map(|i| member_line(&i.member));
+            quote! (
+                pub fn #f() -> &'static [u32] {
+                    &[#(#gates #l),*]
+                }
+            )
+        });
+        let bits = container.bits.then(|| {
+            if fievars.len() > 64 {
+                abort_call_site!("`bits` supports at most 64 names, found {}", fievars.len());
+            }
+
+            let n = stringify!($fn_name);
+            let c = quote::format_ident!("{}_MASK", n[..n.len() - 1].to_uppercase());
+            let mask = match fievars.len() {
+                64 => u64::MAX,
+                l => (1u64 << l) - 1,
+            };
+            let b = 0..fievars.len() as u32;
+            quote! (
+                pub const #c: u64 = #mask;
+
+                #[allow(unreachable_patterns)]
+                pub fn bit_of(name: &str) -> ::core::option::Option<u32> {
+                    match name {
+                        #(#fievars => ::core::option::Option::Some(#b),)*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            )
+        });
+        let renamed = container.renamed.then(|| {
+            let f = quote::format_ident!("renamed_{}", stringify!($fn_name));
+            let r = items
+                .iter()
+                .map(|i| member_name(&i.member))
+                .zip(&fievars)
+                .zip(&gates)
+                .filter(|((i, n), _)| i != *n)
+                .map(|((i, _), g)| quote!(#g #i));
+            quote! (
+                pub fn #f() -> &'static [&'static str] {
+                    &[#(#r),*]
+                }
+            )
+        });
+        let ordinals = container.ordinals.then(|| {
+            let n = stringify!($fn_name);
+            let f = quote::format_ident!("{}_ordinals", &n[..n.len() - 1]);
+            let o = ordinals(&items);
+            quote! (
+                pub fn #f() -> &'static [(&'static str, u32)] {
+                    &[#(#gates (#fievars, #o)),*]
+                }
+            )
+        });
+        let variant_fields = container.variant_fields.then(|| {
+            let inner = items.iter().map(|i| &i.inner);
+            quote! (
+                pub fn fields_by_variant() -> &'static [(&'static str, &'static [&'static str])] {
+                    &[#(#gates (#fievars, &[#(#inner),*])),*]
+                }
+            )
+        });
+        let pairs = container.pairs.then(|| {
+            let n = stringify!($fn_name);
+            let f = quote::format_ident!("{}_name_pairs", &n[..n.len() - 1]);
+            let idents = items.iter().map(|i| member_name(&i.member));
+            quote! (
+                pub fn #f() -> &'static [(&'static str, &'static str)] {
+                    &[#(#gates (#idents, #fievars)),*]
+                }
+            )
+        });
+        let setters = container.setters.as_ref().map(|prefix| {
+            let s = items.iter().map(|i| {
+                let expr = LitStr::new("c|_", proc_macro2::Span::call_site());
+                format!("{}{}", prefix, tr_expr(member_name(&i.member), ExprParser::parse(expr)))
+            });
+            quote! (
+                pub fn setter_names() -> &'static [&'static str] {
+                    &[#(#gates #s),*]
+                }
+            )
+        });
+        let env_aliases = container.env_aliases.then(|| {
+            let a = fievars.iter().map(|n| {
+                let expr = LitStr::new("C|_", proc_macro2::Span::call_site());
+                tr_expr(n.clone(), ExprParser::parse(expr))
+            });
+            quote! (
+                pub fn env_aliases() -> &'static [&'static str] {
+                    &[#(#gates #a),*]
+                }
+            )
+        });
+        let validate = container.validate.then(|| {
+            quote! (
+                pub fn validate(&self) -> ::core::result::Result<(), ::std::vec::Vec<&'static str>> {
+                    let failed = Self::#method()
+                        .iter()
+                        .copied()
+                        .filter(|n| !self.validate_field(n))
+                        .collect::<::std::vec::Vec<_>>();
+
+                    match failed.is_empty() {
+                        true => ::core::result::Result::Ok(()),
+                        false => ::core::result::Result::Err(failed),
+                    }
+                }
+            )
+        });
+        let types = container.types.then(|| {
+            let t = items
+                .iter()
+                .zip(&gates)
+                .filter_map(|(i, g)| i.ty.as_ref().map(|ty| (type_string(ty), g)))
+                .map(|(t, g)| quote!(#g #t));
+            quote! (
+                pub fn field_types() -> &'static [&'static str] {
+                    &[#(#t),*]
+                }
+            )
+        });
+        let get = container.get.then(|| {
+            let tys = items.iter().filter_map(|i| i.ty.as_ref()).collect::<Vec<_>>();
+            let ty = match tys.first() {
+                Some(ty) => *ty,
+                None => abort_call_site!("`get` needs at least one field"),
+            };
+            for t in &tys {
+                if quote!(#t).to_string() != quote!(#ty).to_string() {
+                    abort!(t, "`get` requires every field to have the same type as `{}`", quote!(#ty));
+                }
+            }
+
+            let idents = items.iter().map(|i| &i.member);
+            quote! (
+                #[allow(unreachable_patterns)]
+                pub fn get(&self, name: &str) -> ::core::option::Option<&#ty> {
+                    match name {
+                        #(#gates #fievars => ::core::option::Option::Some(&self.#idents),)*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            )
+        });
+        let set_fields = container.set_fields.then(|| {
+            let idents = items.iter().map(|i| &i.member);
+            quote! (
+                pub fn set_fields(&self) -> ::std::vec::Vec<&'static str> {
+                    let mut v = ::std::vec::Vec::new();
+                    #(
+                        #gates
+                        if self.#idents.is_some() {
+                            v.push(#fievars);
+                        }
+                    )*
+                    v
+                }
+            )
+        });
+        let by_type = container.by_type.iter().map(|(k, t)| {
+            let f = quote::format_ident!("{}_{}", k, stringify!($fn_name), span = k.span());
+            let v = items
+                .iter()
+                .zip(&entries)
+                .filter(|(i, _)| i.ty.as_ref().is_some_and(|ty| type_is(ty, &t.value())))
+                .map(|(_, e)| e);
+            quote! (
+                pub fn #f() -> &'static [&'static str] {
+                    &[#(#v),*]
+                }
+            )
+        });
+
+        let computed = (!container.computed.is_empty()).then(|| {
+            let f = quote::format_ident!("computed_{}", stringify!($fn_name));
+            let c = container.computed.iter().map(|(n, w)| match w {
+                Some(f) => quote!(#[cfg(feature = #f)] #n),
+                None => quote!(#n),
+            });
+            quote! (
+                pub fn #f() -> &'static [&'static str] {
+                    &[#(#c),*]
+                }
+            )
+        });
+
+        let prefixes = items
+            .iter()
+            .zip(&gates)
+            .filter_map(|(i, g)| dynamic_prefix(i).map(|p| quote!(#g #p)))
+            .collect::<Vec<_>>();
+        let dynamic_prefixes = (!prefixes.is_empty()).then(|| {
+            quote! (
+                pub fn dynamic_prefixes() -> &'static [&'static str] {
+                    &[#(#prefixes),*]
+                }
+            )
+        });
+
+        let (const_item, body) = match &container.const_name {
+            Some(c) => (
+                Some(quote!(pub const #c: &'static [&'static str] = &[#(#entries),*];)),
+                quote!(Self::#c),
+            ),
+            None => (None, quote!(&[#(#entries),*])),
+        };
+        let impl_trait = container.impl_trait.as_ref().map(|t| {
+            quote! (
+                impl #impl_generics #t for #strenm #ty_generics #where_clause {
+                    fn #method() -> &'static [&'static str] {
+                        Self::#method()
+                    }
+                }
+            )
+        });
+
+        let n = stringify!($fn_name);
+        let count = quote::format_ident!("{}_count", &n[..n.len() - 1]);
+
+        quote! (
+            impl #impl_generics #strenm #ty_generics #where_clause {
+                #const_item
+
+                #vis fn #method() -> &'static [&'static str] {
+                    #body
+                }
+
+                #vis const fn #count() -> usize {
+                    <[&str]>::len(&[#(#entries),*])
+                }
+
+                #hash
+
+                #joined
+
+                #iter
+
+                #owned
+
+                #array
+
+                #sorted
+
+                #lookup
+
+                #index
+
+                #contains
+
+                #set_fields
+
+                #get
+
+                #types
+
+                #validate
+
+                #cstr
+
+                #phf
+
+                #lines
+
+                #env_aliases
+
+                #setters
+
+                #pairs
+
+                #bits
+
+                #ordinals
+
+                #variant_fields
+
+                #renamed
+
+                #(#by_type)*
+
+                #dynamic_prefixes
+
+                #computed
+            }
+
+            #impl_trait
+
+            #module_const
+
+            #marker
+
+            #preview
+        )
+    }};
+}
+
+/// Implements a `fields` method on structs that return an arry slice of struct field names.
+#[proc_macro_derive(Fields, attributes(fievar, serde))]
+#[proc_macro_error]
+pub fn fields(item: TokenStream) -> TokenStream {
+    let DeriveInput {
+        ident,
+        generics,
+        data,
+        attrs,
+        ..
+    } = parse_macro_input!(item as DeriveInput);
+
+    let (container, items, na) = field_names(&ident, data, attrs);
+
+    gen_impl!(ident, generics, container, items, na, fields).into()
+}
+
+/// Implements a `variants` method on enums that return an arry slice of enum variant names.
+#[proc_macro_derive(Variants, attributes(fievar, serde))]
+#[proc_macro_error]
+pub fn variants(item: TokenStream) -> TokenStream {
+    let DeriveInput {
+        ident,
+        generics,
+        data,
+        attrs,
+        ..
+    } = parse_macro_input!(item as DeriveInput);
+
+    let (container, items, na) = variant_names(&ident, data, attrs);
+
+    gen_impl!(ident, generics, container, items, na, variants).into()
+}
+
+/// Implements a `names` method returning struct field names or enum variant names.
+#[proc_macro_derive(Names, attributes(fievar, serde))]
+#[proc_macro_error]
+pub fn names(item: TokenStream) -> TokenStream {
+    let DeriveInput {
+        ident,
+        generics,
+        data,
+        attrs,
+        ..
+    } = parse_macro_input!(item as DeriveInput);
+
+    let (container, items, na) = match data {
+        Data::Struct(_) => field_names(&ident, data, attrs),
+        Data::Enum(_) => variant_names(&ident, data, attrs),
+        Data::Union(u) => abort!(
+            u.union_token,
+            "`Names` can only be derived for structs and enums"
+        ),
+    };
+
+    gen_impl!(ident, generics, container, items, na, names).into()
+}
+
+/// Reads the container attributes and names the fields of a struct.
+fn field_names(
+    ident: &Ident,
+    data: Data,
+    attrs: Vec<Attribute>,
+) -> (Container, Vec<Item>, Vec<String>) {
+    let mut container = get_container(attrs);
+    if container.qualified_variants.is_some() {
+        abort_call_site!("`qualified_variants` can only be used with `Variants`");
+    }
+    if container.variant_fields {
+        abort_call_site!("`variant_fields` can only be used with `Variants`");
+    }
+
+    let items = without_skipped(only(
+        &container,
+        sort_after(get_field_attr_pairs(&container, data)),
+    ));
+    let na = to_names(&container, ident, &items);
+    let (items, na) = sort_names(&container, items, na);
+    let (items, na) = split_computed(&mut container, items, na);
+
+    if container.set_fields {
+        for ty in items.iter().filter_map(|i| i.ty.as_ref()) {
+            if !is_option(ty) {
+                abort!(ty, "`set_fields` requires every field to be an `Option`");
+            }
+        }
+    }
+
+    (container, items, na)
+}
+
+/// Reads the container attributes and names the variants of an enum.
+fn variant_names(
+    ident: &Ident,
+    data: Data,
+    attrs: Vec<Attribute>,
+) -> (Container, Vec<Item>, Vec<String>) {
+    let mut container = get_container(attrs);
+    if let Some((k, _)) = container.by_type.first() {
+        abort!(k, "`by_type` can only be used with `Fields`");
+    }
+    if container.set_fields {
+        abort_call_site!("`set_fields` can only be used with `Fields`");
+    }
+    if container.get {
+        abort_call_site!("`get` can only be used with `Fields`");
+    }
+    if container.types {
+        abort_call_site!("`types` can only be used with `Fields`");
+    }
+    if container.setters.is_some() {
+        abort_call_site!("`setters` can only be used with `Fields`");
+    }
+
+    let items = without_skipped(only(
+        &container,
+        sort_after(get_variant_attr_pairs(&container, ident, data)),
+    ));
+    let na = to_names(&container, ident, &items);
+    let (items, na) = sort_names(&container, items, na);
+    let (items, na) = split_computed(&mut container, items, na);
+
+    (container, items, na)
+}
+
+/// Expands to a string literal describing what the given transform does.
+#[proc_macro]
+#[proc_macro_error]
+pub fn describe_transform(item: TokenStream) -> TokenStream {
+    let expr = ExprParser::parse(parse_macro_input!(item as LitStr));
+    let d = utils::describe(&expr);
+
+    quote!(#d).into()
+}
+
+/// Checks a transform, expanding to nothing.
+#[proc_macro]
+#[proc_macro_error]
+pub fn fievar_check(item: TokenStream) -> TokenStream {
+    ExprParser::parse(parse_macro_input!(item as LitStr));
+
+    TokenStream::new()
+}
+
+/// Orders items so that each comes after the items named by its `after` keys, keeping
+/// declaration order otherwise.
+fn sort_after(items: Vec<Item>) -> Vec<Item> {
+    let idents = items
+        .iter()
+        .map(|i| member_name(&i.member))
+        .collect::<Vec<_>>();
+
+    let deps = items
+        .iter()
+        .map(|i| {
+            let attr = match &i.attr {
+                Some(a) => a,
+                None => return vec![],
+            };
+
+            nested(attr)
+                .filter_map(|nm| match nm {
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("after") => {
+                        Some(nv_str(nv.lit))
+                    }
+                    _ => None,
+                })
+                .map(|v| match idents.iter().position(|i| *i == v.value()) {
+                    Some(p) => (p, v),
+                    None => abort!(v, "no field or variant named `{}`", v.value()),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut done = vec![false; items.len()];
+    let mut order = vec![];
+
+    while order.len() < items.len() {
+        let next = (0..items.len()).find(|&i| !done[i] && deps[i].iter().all(|(d, _)| done[*d]));
+
+        match next {
+            Some(i) => {
+                done[i] = true;
+                order.push(i);
+            }
+            None => {
+                let i = (0..items.len()).find(|&i| !done[i]).unwrap();
+                let (_, v) = deps[i].iter().find(|(d, _)| !done[*d]).unwrap();
+                abort!(v, "`after` constraints form a cycle");
+            }
+        }
+    }
+
+    let mut items = items.into_iter().map(Some).collect::<Vec<_>>();
+    order
+        .into_iter()
+        .map(|i| items[i].take().unwrap())
+        .collect()
+}
+
+/// Moves the names of `computed` items into the container, returning the rest.
+fn split_computed(
+    container: &mut Container,
+    items: Vec<Item>,
+    names: Vec<String>,
+) -> (Vec<Item>, Vec<String>) {
+    let (computed, stored) = items
+        .into_iter()
+        .zip(names)
+        .partition::<Vec<_>, _>(|(i, _)| is_computed(i));
+
+    container.computed = computed.into_iter().map(|(i, n)| (n, when(&i))).collect();
+    stored.into_iter().unzip()
+}
+
+fn is_computed(item: &Item) -> bool {
+    has_flag(item, "computed")
+}
+
+/// Keeps only the items named by `only`, matching their Rust identifiers.
+fn only(container: &Container, items: Vec<Item>) -> Vec<Item> {
+    let only = match &container.only {
+        Some(o) => o,
+        None => return items,
+    };
+
+    for o in only {
+        if !items.iter().any(|i| member_name(&i.member) == o.value()) {
+            abort!(o, "no field or variant named `{}`", o.value());
+        }
+    }
+
+    items
+        .into_iter()
+        .filter(|i| only.iter().any(|o| member_name(&i.member) == o.value()))
+        .collect()
+}
+
+/// Removes items marked with `skip`.
+fn without_skipped(items: Vec<Item>) -> Vec<Item> {
+    items.into_iter().filter(|i| !has_flag(i, "skip")).collect()
+}
+
+fn has_flag(item: &Item, flag: &str) -> bool {
+    item.attr.as_ref().is_some_and(|a| {
+        nested(a).any(|nm| matches!(nm, NestedMeta::Meta(Meta::Path(p)) if p.is_ident(flag)))
+    })
+}
+
+/// Sorts items by their names when `sort = "alpha"` is set, keeping the two aligned.
+fn sort_names(
+    container: &Container,
+    items: Vec<Item>,
+    names: Vec<String>,
+) -> (Vec<Item>, Vec<String>) {
+    if !container.sort_alpha {
+        return (items, names);
+    }
+
+    let mut pairs = items.into_iter().zip(names).collect::<Vec<_>>();
+    pairs.sort_by(|(_, a), (_, b)| a.cmp(b));
+    pairs.into_iter().unzip()
+}
+
+fn to_names(container: &Container, ty: &Ident, items: &[Item]) -> Vec<String> {
+    let mut bases = items
+        .iter()
+        .map(|i| match &i.member {
+            Member::Named(i) => i.unraw().to_string(),
+            Member::Unnamed(i) => (i.index as usize + container.index_base).to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    if container.strip_common_prefix {
+        strip_common_prefix(&mut bases);
+    }
+
+    let qualifier = match &container.qualified_variants {
+        Some(sep) => {
+            let ty = ty.unraw().to_string();
+            let ty = match container.heck {
+                Some(case) => utils::heck(&ty, case, &container.keep_words),
+                None => ty,
+            };
+            format!("{}{}", ty, sep)
+        }
+        None => String::new(),
+    };
+
+    let names = bases
+        .into_iter()
+        .zip(items)
+        .map(|(b, i)| to_name(container, &qualifier, b, i))
+        .collect::<Vec<_>>();
+
+    if container.deny_duplicates {
+        deny_duplicates(items, &names);
+    }
+
+    if let Some(p) = &container.pattern {
+        for (item, name) in items.iter().zip(&names) {
+            if !p.is_match(name) {
+                abort!(
+                    item.member,
+                    "name `{}` does not match pattern `{}`",
+                    name,
+                    p.input.value()
+                );
+            }
+        }
+    }
+
+    names
+}
+
+fn deny_duplicates(items: &[Item], names: &[String]) {
+    for (i, name) in names.iter().enumerate() {
+        if let Some(first) = names[..i].iter().position(|n| n == name) {
+            let (a, b) = (&items[first].member, &items[i].member);
+            abort!(
+                b,
+                "duplicate name `{}` for `{}` and `{}`", name, member_name(a), member_name(b);
+                note = a.span() => "`{}` is also named `{}` here", member_name(a), name
+            );
+        }
+    }
+}
+
+fn strip_common_prefix(bases: &mut [String]) {
+    let spans = bases
+        .iter()
+        .map(|n| FievarParser::spans(n, NumAlign::Left))
+        .collect::<Vec<_>>();
+
+    let word = |i: usize, w: usize| {
+        let (s, e) = spans[i][w];
+        &bases[i][s..=e]
+    };
+
+    let min = spans.iter().map(|s| s.len()).min().unwrap_or(0);
+    let common = (0..min)
+        .take_while(|&w| (1..bases.len()).all(|i| word(i, w) == word(0, w)))
+        .count();
+
+    if common == 0 {
+        return;
+    }
+
+    for (i, name) in bases.iter_mut().enumerate() {
+        if let Some(&(s, _)) = spans[i].get(common) {
+            *name = name[s..].to_string();
+        }
+    }
+}
+
+fn to_name(container: &Container, qualifier: &str, name: String, item: &Item) -> String {
+    let attr = item.attr.as_ref();
+    let mut pad = container.pad;
+
+    let serde_name = match &item.serde_name {
+        Some(n) if container.use_serde && !renames(attr) => Some(n.value()),
+        _ => None,
+    };
+    let renamed = renames(attr) || serde_name.is_some();
+    let name = serde_name.unwrap_or(name);
+
+    let transformed = attr_str(attr, "transform").is_some()
+        || attr_str(attr, "case").is_some()
+        || (container.transform.is_some() && !renamed);
+    if !transformed {
+        deny_transform_options(attr);
+    }
+
+    let name = match container.heck {
+        Some(case) if !renamed => utils::heck(&name, case, &container.keep_words),
+        _ => name,
+    };
+
+    // Only fields have a type, and serde renames fields and variants differently.
+    let name = match (container.serde_rule, &item.ty) {
+        (Some(rule), Some(_)) if !renamed => utils::serde_field(&name, rule),
+        (Some(rule), None) if !renamed => utils::serde_variant(&name, rule),
+        _ => name,
+    };
+
+    let name = match &container.title_case {
+        Some(small) if !renamed => utils::title_case(&name, small),
+        _ => name,
+    };
+
+    let name = match &container.transform {
+        Some(t) if !renamed => {
+            let mut expr = ExprParser::parse(t.clone());
+            expr.opts = expr_opts(container, attr);
+            tr_expr(name, expr)
+        }
+        _ => name,
+    };
+
+    let name = match attr {
+        None => name,
+        Some(a) => tr(name, a, &mut pad, &expr_opts(container, attr)),
+    };
+
+    let prefix = attr_str(attr, "prefix").map(|p| p.value());
+    let suffix = attr_str(attr, "suffix").map(|s| s.value());
+    let name = format!(
+        "{}{}{}{}",
+        qualifier,
+        prefix
+            .as_ref()
+            .or(container.prefix.as_ref())
+            .map_or("", |p| p),
+        name,
+        suffix
+            .as_ref()
+            .or(container.suffix.as_ref())
+            .map_or("", |s| s),
+    );
+    let name = match &container.ident_safe {
+        Some(p) if name.starts_with(|c: char| c.is_ascii_digit()) => format!("{}{}", p, name),
+        _ => name,
+    };
+    let name = match container.deascii {
+        true => utils::fold_latin(&name),
+        false => name,
+    };
+    let name = match container.graphql_safe && name.starts_with("__") {
+        true => format!("_{}", name.trim_start_matches('_')),
+        false => name,
+    };
+
+    pad_name(name, pad)
+}
+
+/// Whether a field attribute sets its own `name` or `transform`, overriding the container.
+fn renames(attr: Option<&Attribute>) -> bool {
+    attr.is_some_and(|a| {
+        nested(a).any(|nm| match nm {
+            NestedMeta::Meta(Meta::NameValue(nv)) => {
+                nv.path.is_ident("name")
+                    || nv.path.is_ident("transform")
+                    || nv.path.is_ident("case")
+                    || nv.path.is_ident("dynamic_prefix")
+            }
+            _ => false,
+        })
+    })
+}
+
+fn pad_name(name: String, pad: Pad) -> String {
+    let name = match pad.max_len {
+        Some(max) => truncate(name, max, pad.max_len_hash),
+        None => name,
+    };
+
+    let width = match pad.width {
+        Some(w) => w,
+        None => return name,
+    };
+
+    let len = name.chars().count();
+    if len >= width {
+        return match pad.truncate {
+            true => name.chars().take(width).collect(),
+            false => name,
+        };
+    }
+
+    let fill = pad.fill.unwrap_or(' ').to_string().repeat(width - len);
+    match pad.align.unwrap_or(PadAlign::Left) {
+        PadAlign::Left => format!("{}{}", name, fill),
+        PadAlign::Right => format!("{}{}", fill, name),
+    }
+}
+
+fn truncate(name: String, max: usize, hash: bool) -> String {
+    if name.chars().count() <= max {
+        return name;
+    }
+
+    match hash {
+        false => name.chars().take(max).collect(),
+        true => {
+            let suffix = format!(
+                "_{:06x}",
+                utils::fnv(std::slice::from_ref(&name)) & 0xff_ffff
+            );
+            let keep = max.saturating_sub(suffix.len());
+            name.chars()
+                .take(keep)
+                .chain(suffix.chars())
+                .take(max)
+                .collect()
+        }
+    }
+}
+
+fn tr(mut name: String, attr: &Attribute, pad: &mut Pad, opts: &ExprOpts) -> String {
+    if let (Some(c), Some(_)) = (
+        attr_str(Some(attr), "case"),
+        attr_str(Some(attr), "transform"),
+    ) {
+        abort!(c, "`case` and `transform` cannot be used together"; help = "remove one of them");
+    }
+
+    let mut nvs = vec![];
+
+    for nm in nested(attr) {
+        match nm {
+            NestedMeta::Lit(l) => abort!(
+                l,
+                "unexpected literal `{}`", quote!(#l);
+                help = "expected `key = value` pairs"
+            ),
+            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("pad_truncate") => pad.truncate = true,
+            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("max_len_hash") => {
+                pad.max_len_hash = true
+            }
+            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("preserve_separators") => {}
+            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("computed") || p.is_ident("skip") => {}
+            NestedMeta::Meta(Meta::Path(p)) => abort!(
+                p,
+                "unexpected attribute `{}`", quote!(#p);
+                help = "expected `key = value` pairs"
+            ),
+            NestedMeta::Meta(Meta::List(ml)) => unexpected_list(ml, FIELD_KEYS),
+            NestedMeta::Meta(Meta::NameValue(nv)) => nvs.push(nv),
+        }
+    }
+
+    // Whatever order they are written in, `name` sets the base that `transform`/`case`
+    // then change, and `upper_first` and `dynamic_prefix` come last.
+    nvs.sort_by_key(|nv| match nv_key(nv).as_str() {
+        "transform" | "case" => 1,
+        "upper_first" => 2,
+        "dynamic_prefix" => 3,
+        _ => 0,
+    });
+    for nv in nvs {
+        name = tr_nv(name, nv, pad, opts);
+    }
+
+    name
+}
+
+/// Aborts on options that only change how a transform is applied.
+fn deny_transform_options(attr: Option<&Attribute>) {
+    let attr = match attr {
+        Some(a) => a,
+        None => return,
+    };
+
+    for nm in nested(attr) {
+        let path = match &nm {
+            NestedMeta::Meta(Meta::NameValue(nv))
+                if nv.path.is_ident("wrap_first") || nv.path.is_ident("wrap_last") =>
+            {
+                &nv.path
+            }
+            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("preserve_separators") => p,
+            _ => continue,
+        };
+        abort!(
+            path,
+            "`{}` needs a `transform` or `case`", quote!(#path);
+            help = "add one to the field/variant or a `transform` to the container"
+        );
+    }
 }
 
-/// Implements a `fields` method on structs that return an arry slice of struct field names.
-#[proc_macro_derive(Fields, attributes(fievar))]
-#[proc_macro_error]
-pub fn fields(item: TokenStream) -> TokenStream {
-    let DeriveInput { ident, data, .. } = parse_macro_input!(item as DeriveInput);
+fn expr_opts(container: &Container, attr: Option<&Attribute>) -> ExprOpts {
+    let mut opts = ExprOpts {
+        acronyms: container.acronyms.clone(),
+        keep_words: container.keep_words.clone(),
+        keep_leading_underscore: container.keep_leading_underscore,
+        ..Default::default()
+    };
+    let attr = match attr {
+        Some(a) => a,
+        None => return opts,
+    };
 
-    let na = get_field_attr_pairs(data)
-        .into_iter()
-        .map(to_name)
-        .collect::<Vec<_>>();
+    for nm in nested(attr) {
+        match nm {
+            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("preserve_separators") => {
+                opts.preserve_separators = true
+            }
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("wrap_first") => {
+                opts.wrap_first = Some(nv_str(nv.lit).value())
+            }
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("wrap_last") => {
+                opts.wrap_last = Some(nv_str(nv.lit).value())
+            }
+            _ => {}
+        }
+    }
 
-    gen_impl!(ident, na, fields).into()
+    opts
 }
 
-/// Implements a `variants` method on enums that return an arry slice of enum variant names.
-#[proc_macro_derive(Variants, attributes(fievar))]
-#[proc_macro_error]
-pub fn variants(item: TokenStream) -> TokenStream {
-    let DeriveInput { ident, data, .. } = parse_macro_input!(item as DeriveInput);
+fn tr_nv(name: String, nv: MetaNameValue, pad: &mut Pad, opts: &ExprOpts) -> String {
+    let key = nv_key(&nv);
 
-    let na = get_variant_attr_pairs(data)
-        .into_iter()
-        .map(to_name)
-        .collect::<Vec<_>>();
+    match key.as_str() {
+        "name" => nv_str(nv.lit).value(),
+        "transform" => {
+            let mut expr = ExprParser::parse(nv_str(nv.lit));
+            expr.opts = opts.clone();
+            tr_expr(name, expr)
+        }
+        "case" => {
+            let val = nv_str(nv.lit);
+            let t = match val.value().as_str() {
+                "snake" => "c|_",
+                "camel" => "c Cc",
+                "pascal" => "Cc",
+                "kebab" => "c|-",
+                "screaming_snake" => "C|_",
+                "screaming_kebab" => "C|-",
+                v => abort!(
+                    val,
+                    "unknown case `{}`", v;
+                    help = "expected one of `snake`, `camel`, `pascal`, `kebab`, \
+                            `screaming_snake` or `screaming_kebab`"
+                ),
+            };
 
-    gen_impl!(ident, na, variants).into()
+            let mut expr = ExprParser::parse(LitStr::new(t, val.span()));
+            expr.opts = opts.clone();
+            tr_expr(name, expr)
+        }
+        "upper_first" => upper_first(name, &nv_str(nv.lit).value()),
+        "dynamic_prefix" => format!("{}*", nv_str(nv.lit).value()),
+        "after" | "wrap_first" | "wrap_last" | "when" | "ordinal" | "prefix" | "suffix" => name,
+        "pad" | "pad_char" | "align" | "max_len" => {
+            pad_nv(pad, &key, nv.lit);
+            name
+        }
+        _ => {
+            let p = nv.path;
+            abort!(p, "unrecognized attribute `{}`", quote!(#p))
+        }
+    }
 }
 
-fn to_name((ident, attr): (Ident, Option<Attribute>)) -> String {
-    match attr {
-        None => ident.to_string(),
-        Some(a) => tr(ident.to_string(), a),
+fn upper_first(name: String, word: &str) -> String {
+    let span = FievarParser::spans(&name, NumAlign::Left)
+        .into_iter()
+        .find(|&(b, e)| name[b..=e].eq_ignore_ascii_case(word));
+
+    match span {
+        Some((b, e)) => format!(
+            "{}{}{}",
+            &name[..b],
+            name[b..=e].to_uppercase(),
+            &name[e + 1..]
+        ),
+        None => name,
     }
 }
 
-fn tr(mut name: String, attr: Attribute) -> String {
+fn nested(attr: &Attribute) -> impl Iterator<Item = NestedMeta> {
     let nms = match attr.parse_meta() {
         Ok(Meta::List(MetaList { nested, .. })) => nested,
         Ok(v) => abort!(v, "expected #[{}(...)]", FIEVAR),
         Err(e) => abort!(attr, "expected #[{}(...)], {}", FIEVAR, e),
     };
 
-    for nm in nms.into_iter() {
-        name = match nm {
-            NestedMeta::Lit(l) => abort!(
-                l,
-                "unexpected literal `{}`", quote!(#l);
-                help = "expected `key = value` pairs"
-            ),
-            NestedMeta::Meta(Meta::Path(p)) => abort!(
-                p,
-                "unexpected attribute `{}`", quote!(#p);
-                help = "expected `key = value` pairs"
-            ),
-            NestedMeta::Meta(Meta::List(ml)) => abort!(
-                ml,
-                "unexpected list `{}`", quote!(#ml);
-                help = "expected `key = value` pairs"
-            ),
-            NestedMeta::Meta(Meta::NameValue(nv)) => tr_nv(name, nv),
-        };
-    }
+    nms.into_iter()
+}
 
-    name
+fn unexpected_list(ml: MetaList, keys: &[&str]) -> ! {
+    let key = ml
+        .path
+        .get_ident()
+        .map(|i| i.to_string())
+        .filter(|k| keys.contains(&k.as_str()));
+
+    match (key, ml.nested.first()) {
+        (Some(k), Some(NestedMeta::Lit(l))) if ml.nested.len() == 1 => abort!(
+            ml,
+            "unexpected list `{}`", quote!(#ml);
+            help = "try `{} = {}`", k, quote!(#l)
+        ),
+        (Some(k), _) => abort!(
+            ml,
+            "unexpected list `{}`", quote!(#ml);
+            help = "`{}` expects a value, try `{} = ...`", k, k
+        ),
+        (None, _) => abort!(
+            ml,
+            "unexpected list `{}`", quote!(#ml);
+            help = "expected `key = value` pairs"
+        ),
+    }
 }
 
-fn tr_nv(name: String, nv: MetaNameValue) -> String {
-    let key = match nv.path.get_ident() {
+fn nv_key(nv: &MetaNameValue) -> String {
+    match nv.path.get_ident() {
         Some(i) => i.to_string(),
         None => {
-            let p = nv.path;
+            let p = &nv.path;
             abort!(
                 p,
                 "unexpected attribute `{}`", quote!(#p);
                 help = "expected a valid identifier"
             )
         }
-    };
+    }
+}
 
-    let val = match nv.lit {
+fn nv_str(lit: Lit) -> LitStr {
+    match lit {
         Lit::Str(v) => v,
         l => abort!(
             l,
             "unexpected literal `{}`", quote!(#l);
             help = "expected a string literal"
         ),
+    }
+}
+
+fn nv_int(lit: Lit) -> usize {
+    match lit {
+        Lit::Int(ref v) => match v.base10_parse::<usize>() {
+            Ok(v) => v,
+            Err(e) => abort!(v, "{}", e),
+        },
+        l => abort!(
+            l,
+            "unexpected literal `{}`", quote!(#l);
+            help = "expected an integer literal"
+        ),
+    }
+}
+
+fn pad_nv(pad: &mut Pad, key: &str, lit: Lit) {
+    match key {
+        "pad" => pad.width = Some(nv_int(lit)),
+        "max_len" => pad.max_len = Some(nv_int(lit)),
+        "pad_char" => {
+            let val = nv_str(lit);
+            let v = val.value();
+            let mut chars = v.chars();
+
+            pad.fill = match (chars.next(), chars.next()) {
+                (Some(c), None) => Some(c),
+                _ => abort!(val, "expected a single character"),
+            };
+        }
+        "align" => {
+            let val = nv_str(lit);
+
+            pad.align = match val.value().as_str() {
+                "left" => Some(PadAlign::Left),
+                "right" => Some(PadAlign::Right),
+                _ => abort!(
+                    val,
+                    "unexpected alignment `{}`", val.value();
+                    help = "expected `left` or `right`"
+                ),
+            };
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn get_container(attrs: Vec<Attribute>) -> Container {
+    let mut container = Container::default();
+    let serde = attrs
+        .iter()
+        .filter(|a| a.path.is_ident("serde"))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let attr = match get_attr(attrs) {
+        Some(a) => a,
+        None => return container,
     };
 
+    // Each of these renames every name on its own, so only one of them may be given.
+    let mut renaming: Option<String> = None;
+    for nm in nested(&attr) {
+        let key = match &nm {
+            NestedMeta::Meta(m) => m.path().get_ident().map(|i| i.to_string()),
+            NestedMeta::Lit(_) => None,
+        };
+        if let Some(k) = key.filter(|k| RENAMING_KEYS.contains(&k.as_str())) {
+            if let Some(r) = &renaming {
+                abort!(
+                    nm,
+                    "`{}` and `{}` cannot be used together", r, k;
+                    help = "remove one of them"
+                );
+            }
+            renaming = Some(k);
+        }
+
+        match nm {
+            NestedMeta::Meta(Meta::NameValue(nv)) => container_nv(&mut container, nv),
+            NestedMeta::Meta(Meta::Path(p)) => container_path(&mut container, p),
+            NestedMeta::Lit(l) => abort!(
+                l,
+                "unexpected literal `{}`", quote!(#l);
+                help = "expected `key = value` pairs or flags"
+            ),
+            NestedMeta::Meta(Meta::List(ml)) if ml.path.is_ident("by_type") => {
+                container_by_type(&mut container, ml)
+            }
+            NestedMeta::Meta(Meta::List(ml)) if ml.path.is_ident("keep_words") => {
+                container_keep_words(&mut container, ml)
+            }
+            NestedMeta::Meta(Meta::List(ml)) if ml.path.is_ident("acronyms") => {
+                container_acronyms(&mut container, ml)
+            }
+            NestedMeta::Meta(Meta::List(ml)) if ml.path.is_ident("only") => {
+                container_only(&mut container, ml)
+            }
+            NestedMeta::Meta(Meta::List(ml)) if ml.path.is_ident("title_case") => {
+                container_title_case(&mut container, ml)
+            }
+            NestedMeta::Meta(Meta::List(ml)) => unexpected_list(ml, CONTAINER_KEYS),
+        }
+    }
+
+    if container.use_serde && container.heck.is_none() {
+        let case = serde_rename_all(&serde);
+        if let (Some(c), Some(r)) = (&case, &renaming) {
+            abort!(
+                c,
+                "`#[serde(rename_all)]` and `{}` cannot be used together", r;
+                help = "remove one of them, or `use_serde`"
+            );
+        }
+        container.serde_rule = case.map(|c| serde_rule(&c));
+    }
+
+    container
+}
+
+fn container_by_type(container: &mut Container, ml: MetaList) {
+    for nm in ml.nested {
+        match nm {
+            NestedMeta::Meta(Meta::NameValue(nv)) => {
+                let key = match nv.path.get_ident() {
+                    Some(i) => i.clone(),
+                    None => {
+                        let p = nv.path;
+                        abort!(p, "expected a method name, like `string = \"String\"`")
+                    }
+                };
+                container.by_type.push((key, nv_str(nv.lit)));
+            }
+            nm => abort!(
+                nm,
+                "unexpected `{}`", quote!(#nm);
+                help = "expected `name = \"Type\"` pairs, like `string = \"String\"`"
+            ),
+        }
+    }
+}
+
+fn container_keep_words(container: &mut Container, ml: MetaList) {
+    for nm in ml.nested {
+        match nm {
+            NestedMeta::Lit(Lit::Str(s)) => container.keep_words.push(s.value()),
+            nm => abort!(
+                nm,
+                "unexpected `{}`", quote!(#nm);
+                help = "expected string literals, like `keep_words(\"ID\")`"
+            ),
+        }
+    }
+}
+
+fn container_acronyms(container: &mut Container, ml: MetaList) {
+    for nm in ml.nested {
+        match nm {
+            NestedMeta::Lit(Lit::Str(s)) => container.acronyms.push(s.value()),
+            nm => abort!(
+                nm,
+                "unexpected `{}`", quote!(#nm);
+                help = "expected string literals, like `acronyms(\"ID\")`"
+            ),
+        }
+    }
+}
+
+fn container_only(container: &mut Container, ml: MetaList) {
+    let only = container.only.get_or_insert_with(Vec::new);
+
+    for nm in ml.nested {
+        match nm {
+            NestedMeta::Lit(Lit::Str(s)) => only.push(s),
+            nm => abort!(
+                nm,
+                "unexpected `{}`", quote!(#nm);
+                help = "expected string literals, like `only(\"id\")`"
+            ),
+        }
+    }
+}
+
+fn container_title_case(container: &mut Container, ml: MetaList) {
+    let words = container.title_case.get_or_insert_with(Vec::new);
+
+    for nm in ml.nested {
+        match nm {
+            NestedMeta::Lit(Lit::Str(s)) => words.push(s.value().to_lowercase()),
+            nm => abort!(
+                nm,
+                "unexpected `{}`", quote!(#nm);
+                help = "expected string literals, like `title_case(\"of\", \"the\")`"
+            ),
+        }
+    }
+}
+
+/// Renders a type the way it is usually written, like `Vec<u8>` or `&'a str`.
+fn type_string(ty: &Type) -> String {
+    let mut s = quote!(#ty).to_string();
+
+    for (from, to) in [
+        (" < ", "<"),
+        ("< ", "<"),
+        (" >", ">"),
+        (" ,", ","),
+        (" ;", ";"),
+        (" :: ", "::"),
+        (":: ", "::"),
+        ("& ", "&"),
+        ("( ", "("),
+        (" )", ")"),
+        ("[ ", "["),
+        (" ]", "]"),
+    ] {
+        s = s.replace(from, to);
+    }
+
+    s
+}
+
+fn type_is(ty: &Type, pat: &str) -> bool {
+    let norm = |s: &str| s.split_whitespace().collect::<String>();
+    let pat = norm(pat);
+
+    if norm(&quote!(#ty).to_string()) == pat {
+        return true;
+    }
+
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => path
+            .segments
+            .last()
+            .is_some_and(|s| s.arguments.is_empty() && s.ident == pat),
+        _ => false,
+    }
+}
+
+fn container_path(container: &mut Container, p: Path) {
+    match p.get_ident().map(|i| i.to_string()).as_deref() {
+        Some("strip_common_prefix") => container.strip_common_prefix = true,
+        Some("hash") => container.hash = true,
+        Some("skip_phantom") => container.skip_phantom = true,
+        Some("iter") => container.iter = true,
+        Some("owned") => container.owned = true,
+        Some("array") => container.array = true,
+        Some("preview") => container.preview = true,
+        Some("sorted") => container.sorted = true,
+        Some("deny_duplicates") => container.deny_duplicates = true,
+        Some("lookup") => container.lookup = true,
+        Some("index") => container.index = true,
+        Some("contains") => container.contains = true,
+        Some("use_serde") => container.use_serde = true,
+        Some("pub_only") => container.pub_only = true,
+        Some("module_const") => container.module_const_auto = true,
+        Some("marker") => container.marker = true,
+        Some("set_fields") => container.set_fields = true,
+        Some("validate") => container.validate = true,
+        Some("lines") if cfg!(feature = "lines") => container.lines = true,
+        Some("lines") => abort!(p, "`lines` requires the `lines` feature of fievar"),
+        Some("env_aliases") => container.env_aliases = true,
+        Some("pairs") => container.pairs = true,
+        Some("bits") => container.bits = true,
+        Some("ordinals") => container.ordinals = true,
+        Some("graphql_safe") => container.graphql_safe = true,
+        Some("renamed") => container.renamed = true,
+        Some("get") => container.get = true,
+        Some("types") => container.types = true,
+        Some("variant_fields") => container.variant_fields = true,
+        Some("keep_leading_underscore") => container.keep_leading_underscore = true,
+        Some("title_case") => {
+            container.title_case = Some(utils::SMALL_WORDS.iter().map(|w| w.to_string()).collect())
+        }
+        Some("ident_safe") => container.ident_safe = Some("_".to_string()),
+        Some("qualified_variants") => container.qualified_variants = Some("_".to_string()),
+        Some("cstr") if cfg!(feature = "cstr") => container.cstr = true,
+        Some("cstr") => abort!(p, "`cstr` requires the `cstr` feature of fievar"),
+        Some("phf") if cfg!(feature = "phf") => container.phf = true,
+        Some("phf") => abort!(p, "`phf` requires the `phf` feature of fievar"),
+        Some("deascii") if cfg!(feature = "latin_fold") => container.deascii = true,
+        Some("deascii") => abort!(p, "`deascii` requires the `latin_fold` feature of fievar"),
+        Some("pad_truncate") => container.pad.truncate = true,
+        Some("max_len_hash") => container.pad.max_len_hash = true,
+        _ => abort!(p, "unrecognized attribute `{}`", quote!(#p)),
+    }
+}
+
+/// Parses the case of a `#[serde(rename_all = "..")]`.
+fn serde_rule(val: &LitStr) -> RenameRule {
+    match val.value().as_str() {
+        "lowercase" => RenameRule::Lower,
+        "UPPERCASE" => RenameRule::Upper,
+        "PascalCase" => RenameRule::Pascal,
+        "camelCase" => RenameRule::Camel,
+        "snake_case" => RenameRule::Snake,
+        "SCREAMING_SNAKE_CASE" => RenameRule::ScreamingSnake,
+        "kebab-case" => RenameRule::Kebab,
+        "SCREAMING-KEBAB-CASE" => RenameRule::ScreamingKebab,
+        v => abort!(
+            val,
+            "unknown case `{}`", v;
+            help = "expected one of `lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`, \
+                    `snake_case`, `SCREAMING_SNAKE_CASE`, `kebab-case` or `SCREAMING-KEBAB-CASE`"
+        ),
+    }
+}
+
+/// Maps the case names serde uses for `rename_all` to a [`HeckCase`].
+fn rename_all_case(val: &LitStr) -> HeckCase {
+    match val.value().as_str() {
+        "snake_case" => HeckCase::Snake,
+        "kebab-case" => HeckCase::Kebab,
+        "SCREAMING_SNAKE_CASE" => HeckCase::ShoutySnake,
+        "SCREAMING-KEBAB-CASE" => HeckCase::ShoutyKebab,
+        "PascalCase" => HeckCase::Pascal,
+        "camelCase" => HeckCase::LowerCamel,
+        "jackson_camel" => HeckCase::JacksonCamel,
+        v => abort!(
+            val,
+            "unknown case `{}`", v;
+            help = "expected one of `snake_case`, `kebab-case`, `SCREAMING_SNAKE_CASE`, \
+                    `SCREAMING-KEBAB-CASE`, `PascalCase`, `camelCase` or `jackson_camel`"
+        ),
+    }
+}
+
+fn container_nv(container: &mut Container, nv: MetaNameValue) {
+    let key = nv_key(&nv);
+
     match key.as_str() {
-        "name" => val.value(),
-        "transform" => tr_expr(name, val),
+        "module_const" => container.module_const = Some(to_ident(&nv_str(nv.lit), "FIELDS")),
+        "vis" => {
+            let val = nv_str(nv.lit);
+
+            container.vis = match syn::parse_str::<Visibility>(&val.value()) {
+                Ok(v) => Some(v),
+                Err(_) => abort!(
+                    val,
+                    "`{}` is not a valid visibility", val.value();
+                    help = "expected something like `pub(crate)`, or `\"\"` for private"
+                ),
+            };
+        }
+        "impl_trait" => {
+            let val = nv_str(nv.lit);
+
+            container.impl_trait = match syn::parse_str::<Path>(&val.value()) {
+                Ok(p) => Some(p),
+                Err(_) => abort!(
+                    val,
+                    "`{}` is not a valid trait path", val.value();
+                    help = "expected something like `crate::Named`"
+                ),
+            };
+        }
+        "const_name" => container.const_name = Some(to_ident(&nv_str(nv.lit), "FIELDS")),
+        "fn" => container.fn_name = Some(to_ident(&nv_str(nv.lit), "json_keys")),
+        "joined" => container.joined = Some(nv_str(nv.lit)),
+        "transform" => container.transform = Some(nv_str(nv.lit)),
+        "index_base" => container.index_base = nv_int(nv.lit),
+        "qualified_variants" => container.qualified_variants = Some(nv_str(nv.lit).value()),
+        "ident_safe" => container.ident_safe = Some(nv_str(nv.lit).value()),
+        "setters" => container.setters = Some(nv_str(nv.lit).value()),
+        "prefix" => container.prefix = Some(nv_str(nv.lit).value()),
+        "suffix" => container.suffix = Some(nv_str(nv.lit).value()),
+        "pattern" => container.pattern = Some(Pattern::parse(nv_str(nv.lit))),
+        "sort" => {
+            let val = nv_str(nv.lit);
+
+            match val.value().as_str() {
+                "alpha" => container.sort_alpha = true,
+                v => abort!(val, "unknown sort `{}`", v; help = "expected `alpha`"),
+            }
+        }
+        "rename_all" => container.heck = Some(rename_all_case(&nv_str(nv.lit))),
+        "heck" => {
+            let val = nv_str(nv.lit);
+
+            container.heck = Some(match val.value().as_str() {
+                "snake" => HeckCase::Snake,
+                "kebab" => HeckCase::Kebab,
+                "shouty_snake" => HeckCase::ShoutySnake,
+                "shouty_kebab" => HeckCase::ShoutyKebab,
+                "upper_camel" => HeckCase::UpperCamel,
+                "lower_camel" => HeckCase::LowerCamel,
+                "title" => HeckCase::Title,
+                "train" => HeckCase::Train,
+                v => abort!(
+                    val,
+                    "unknown case `{}`", v;
+                    help = "expected one of `snake`, `kebab`, `shouty_snake`, `shouty_kebab`, \
+                            `upper_camel`, `lower_camel`, `title` or `train`"
+                ),
+            });
+        }
+        "pad" | "pad_char" | "align" | "max_len" => pad_nv(&mut container.pad, &key, nv.lit),
         _ => {
             let p = nv.path;
             abort!(p, "unrecognized attribute `{}`", quote!(#p))
@@ -234,102 +4207,414 @@ fn tr_nv(name: String, nv: MetaNameValue) -> String {
     }
 }
 
-fn tr_expr(name: String, expr: LitStr) -> String {
-    let expr = ExprParser::parse(expr);
+fn to_ident(val: &LitStr, example: &str) -> Ident {
+    match syn::parse_str::<Ident>(&val.value()) {
+        Ok(mut i) => {
+            i.set_span(val.span());
+            i
+        }
+        Err(_) => abort!(
+            val,
+            "`{}` is not a valid identifier", val.value();
+            help = "expected something like `{}`", example
+        ),
+    }
+}
+
+fn tr_expr(name: String, expr: Expr) -> String {
     let trs_len = expr.trs.len();
 
-    let words = FievarParser::parse(&name, expr.db);
-    let word_count = words.len();
-    let words = words.into_iter();
+    let spans = FievarParser::spans_keeping(&name, expr.db, &expr.opts.keep_words);
+    let word_count = spans.len();
+    let words = spans.iter().map(|&(b, e, _)| &name[b..=e]);
+    let kept = spans.iter().map(|&(_, _, k)| k).collect::<Vec<_>>();
 
     let tr_char = |txt: &str, tr: Tr| -> String {
         match tr {
             Tr::Upper => txt.to_uppercase(),
             Tr::Lower => txt.to_lowercase(),
+            Tr::UpperVowels => by_vowels(txt, true),
+            Tr::LowerVowels => by_vowels(txt, false),
             Tr::None => txt.to_string(),
         }
     };
 
-    let tr_word = |txt: &str, tr: &TrChars| -> String {
-        let l = txt.len();
-
-        match (tr, l) {
-            (&TrChars::All(tr), _) => tr_char(txt, tr),
-            (&TrChars::FirstRest(f, _), 1) => tr_char(txt, f),
-            (&TrChars::FirstRest(f, r), _) => {
-                format!("{}{}", tr_char(&txt[..1], f), tr_char(&txt[1..], r))
-            }
-            (&TrChars::FirstMiddleLast(f, _, _), 1) => tr_char(txt, f),
-            (&TrChars::FirstMiddleLast(f, m, l), _) => {
-                let second_last = txt.len() - 1;
-                format!(
-                    "{}{}{}",
-                    tr_char(&txt[..1], f),
-                    tr_char(&txt[1..second_last], m),
-                    tr_char(&txt[second_last..], l),
-                )
-            }
+    // Kept words are emitted exactly as written.
+    let tr_word = |i: usize, txt: &str, tr: &TrChars| -> String {
+        if kept[i] {
+            return txt.to_string();
+        }
+        if expr
+            .opts
+            .acronyms
+            .iter()
+            .any(|a| a.eq_ignore_ascii_case(txt))
+        {
+            return txt.to_uppercase();
         }
+
+        let last = txt.chars().count() - 1;
+
+        txt.chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let t = match *tr {
+                    TrChars::All(t) => t,
+                    TrChars::FirstRest(f, _) | TrChars::FirstMiddleLast(f, _, _) if i == 0 => f,
+                    TrChars::FirstRest(_, r) => r,
+                    TrChars::FirstMiddleLast(_, _, l) if i == last => l,
+                    TrChars::FirstMiddleLast(_, m, _) => m,
+                };
+                tr_char(c.encode_utf8(&mut [0; 4]), t)
+            })
+            .collect::<String>()
     };
 
-    match trs_len {
-        0 => words.collect::<Vec<_>>().join(&expr.sep),
+    let words = match trs_len {
+        0 => words.map(str::to_string).collect::<Vec<_>>(),
         1 => words
-            .map(|w| tr_word(w, &expr.trs[0]))
-            .collect::<Vec<_>>()
-            .join(&expr.sep),
+            .enumerate()
+            .map(|(i, w)| tr_word(i, w, &expr.trs[0]))
+            .collect::<Vec<_>>(),
         2 => words
             .enumerate()
             .map(|(i, w)| match i {
-                0 => tr_word(w, &expr.trs[0]),
-                _ => tr_word(w, &expr.trs[1]),
+                0 => tr_word(i, w, &expr.trs[0]),
+                _ => tr_word(i, w, &expr.trs[1]),
             })
-            .collect::<Vec<_>>()
-            .join(&expr.sep),
+            .collect::<Vec<_>>(),
         3 => words
             .enumerate()
             .map(|(i, w)| {
                 if i == 0 {
-                    tr_word(w, &expr.trs[0])
+                    tr_word(i, w, &expr.trs[0])
                 } else if (1..word_count - 1).contains(&i) {
-                    tr_word(w, &expr.trs[1])
+                    tr_word(i, w, &expr.trs[1])
                 } else {
-                    tr_word(w, &expr.trs[2])
+                    tr_word(i, w, &expr.trs[2])
                 }
             })
-            .collect::<Vec<_>>()
-            .join(&expr.sep),
+            .collect::<Vec<_>>(),
         _ => unreachable!(),
+    };
+
+    let mut words = match expr.num_width {
+        Some(width) => words
+            .iter()
+            .zip(&kept)
+            .map(|(w, &k)| match k {
+                true => w.clone(),
+                false => zero_pad(w, width),
+            })
+            .collect(),
+        None => words,
+    };
+    if let Some(w) = &expr.opts.wrap_first {
+        if let Some(first) = words.first_mut() {
+            *first = format!("{}{}{}", w, first, w);
+        }
+    }
+    if let Some(w) = &expr.opts.wrap_last {
+        if let Some(last) = words.last_mut() {
+            *last = format!("{}{}{}", w, last, w);
+        }
+    }
+
+    let numeral = |w: &str| w.chars().all(|c| c.is_ascii_digit());
+
+    let r = match expr.opts.preserve_separators {
+        false => {
+            let mut r = String::new();
+
+            for (i, w) in words.iter().enumerate() {
+                if i > 0 {
+                    let sep = match (&expr.num_sep, &expr.first_sep) {
+                        (Some(n), _) if numeral(&words[i - 1]) || numeral(w) => n,
+                        (_, Some(f)) if i == 1 => f,
+                        _ => &expr.sep,
+                    };
+                    r.push_str(sep);
+                }
+                r.push_str(w);
+            }
+
+            r
+        }
+        true => {
+            let mut r = String::new();
+            let mut prev = 0;
+
+            for (&(b, e, _), w) in spans.iter().zip(words) {
+                r.push_str(&name[prev..b]);
+                r.push_str(&w);
+                prev = e + 1;
+            }
+
+            r.push_str(&name[prev..]);
+            r
+        }
+    };
+
+    // Leading underscores never start a word, `preserve_separators` already keeps them.
+    match expr.opts.keep_leading_underscore
+        && !expr.opts.preserve_separators
+        && name.starts_with('_')
+    {
+        true => format!("_{}", r),
+        false => r,
+    }
+}
+
+/// Pads every run of digits in `word` with leading zeros up to `width`.
+fn zero_pad(word: &str, width: usize) -> String {
+    let mut r = String::new();
+    let mut chars = word.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c.is_ascii_digit() {
+            false => r.push(c),
+            true => {
+                let mut digits = c.to_string();
+                while let Some(d) = chars.next_if(char::is_ascii_digit) {
+                    digits.push(d);
+                }
+                r.push_str(&format!("{:0>1$}", digits, width));
+            }
+        }
     }
+
+    r
+}
+
+fn by_vowels(txt: &str, upper: bool) -> String {
+    txt.chars()
+        .map(|c| match "aeiouAEIOU".contains(c) == upper {
+            true => c.to_uppercase().to_string(),
+            false => c.to_lowercase().to_string(),
+        })
+        .collect()
 }
 
-fn get_variant_attr_pairs(data: Data) -> Vec<(Ident, Option<Attribute>)> {
+fn get_variant_attr_pairs(container: &Container, ident: &Ident, data: Data) -> Vec<Item> {
     let variants = match data {
         Data::Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("this macro can only be applied to enums"),
+        _ => abort!(
+            ident,
+            "`Variants` can only be derived for enums";
+            help = "use `#[derive(Fields)]` for structs"
+        ),
     };
 
     variants
         .into_iter()
-        .map(|v| (v.ident, get_attr(v.attrs)))
+        .enumerate()
+        .map(|(i, v)| Item {
+            index: i,
+            member: Member::Named(v.ident),
+            serde_name: container
+                .use_serde
+                .then(|| serde_rename(&v.attrs))
+                .flatten(),
+            attr: get_attr(v.attrs),
+            ty: None,
+            inner: v
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(i, f)| match &f.ident {
+                    Some(ident) => ident.unraw().to_string(),
+                    None => i.to_string(),
+                })
+                .collect(),
+        })
         .collect::<Vec<_>>()
 }
 
-fn get_field_attr_pairs(data: Data) -> Vec<(Ident, Option<Attribute>)> {
+fn get_field_attr_pairs(container: &Container, data: Data) -> Vec<Item> {
     let fields = match data {
         Data::Struct(DataStruct {
             fields: Fields::Named(FieldsNamed { named, .. }),
             ..
         }) => named,
-        _ => panic!("this macro can only be applied to structs with named fields"),
+        Data::Struct(DataStruct {
+            fields: Fields::Unnamed(FieldsUnnamed { unnamed, .. }),
+            ..
+        }) => unnamed,
+        Data::Struct(DataStruct {
+            fields: Fields::Unit,
+            ..
+        }) => return vec![],
+        Data::Union(DataUnion {
+            fields: FieldsNamed { named, .. },
+            ..
+        }) => named,
+        Data::Enum(e) => abort!(
+            e.enum_token,
+            "`Fields` can only be derived for structs and unions";
+            help = "use `Variants` for enums"
+        ),
     };
 
     fields
         .into_iter()
-        .map(|f| (f.ident.unwrap(), get_attr(f.attrs)))
+        .enumerate()
+        .filter(|(_, f)| !(container.skip_phantom && is_phantom(&f.ty)))
+        .filter(|(_, f)| !container.pub_only || matches!(f.vis, Visibility::Public(_)))
+        .map(|(i, f)| Item {
+            index: i,
+            member: match f.ident {
+                Some(ident) => Member::Named(ident),
+                None => Member::Unnamed(Index {
+                    index: i as u32,
+                    span: f.ty.span(),
+                }),
+            },
+            serde_name: container
+                .use_serde
+                .then(|| serde_rename(&f.attrs))
+                .flatten(),
+            attr: get_attr(f.attrs),
+            ty: Some(f.ty),
+            inner: vec![],
+        })
         .collect::<Vec<_>>()
 }
 
+/// The `dynamic_prefix` of an item, if it has one.
+fn dynamic_prefix(item: &Item) -> Option<LitStr> {
+    item_str(item, "dynamic_prefix")
+}
+
+/// The feature named by the `when` of an item, if it has one.
+fn when(item: &Item) -> Option<LitStr> {
+    item_str(item, "when")
+}
+
+/// The `ordinal` of every item, defaulting to the declaration index. Aborts on duplicates.
+fn ordinals(items: &[Item]) -> Vec<u32> {
+    let explicit = items.iter().map(|i| {
+        nested(i.attr.as_ref()?).find_map(|nm| match nm {
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("ordinal") => Some(nv.lit),
+            _ => None,
+        })
+    });
+
+    let mut seen = vec![];
+    for (item, lit) in items.iter().zip(explicit) {
+        let o = match &lit {
+            Some(l) => nv_int(l.clone()) as u32,
+            None => item.index as u32,
+        };
+
+        if seen.contains(&o) {
+            match lit {
+                Some(l) => abort!(l, "duplicate ordinal `{}`", o),
+                None => abort!(item.member, "duplicate ordinal `{}`", o),
+            }
+        }
+        seen.push(o);
+    }
+
+    seen
+}
+
+fn item_str(item: &Item, key: &str) -> Option<LitStr> {
+    attr_str(item.attr.as_ref(), key)
+}
+
+fn attr_str(attr: Option<&Attribute>, key: &str) -> Option<LitStr> {
+    nested(attr?).find_map(|nm| match nm {
+        NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident(key) => Some(nv_str(nv.lit)),
+        _ => None,
+    })
+}
+
+/// The name a member goes by in Rust.
+fn member_name(member: &Member) -> String {
+    match member {
+        Member::Named(i) => i.unraw().to_string(),
+        Member::Unnamed(i) => i.index.to_string(),
+    }
+}
+
+/// The source line a member is declared on, or 0 when the compiler doesn't expose it.
+#[cfg(feature = "lines")]
+fn member_line(member: &Member) -> u32 {
+    member.span().start().line as u32
+}
+
+#[cfg(not(feature = "lines"))]
+fn member_line(_: &Member) -> u32 {
+    unreachable!("`lines` is rejected without the `lines` feature")
+}
+
+fn cstr_lit(member: &Member, name: &str) -> proc_macro2::Literal {
+    match std::ffi::CString::new(name) {
+        Ok(c) => proc_macro2::Literal::c_string(&c),
+        Err(_) => abort!(
+            member,
+            "name `{}` contains a nul character",
+            name.escape_default()
+        ),
+    }
+}
+
+fn is_phantom(ty: &Type) -> bool {
+    last_segment_is(ty, "PhantomData")
+}
+
+fn is_option(ty: &Type) -> bool {
+    last_segment_is(ty, "Option")
+}
+
+fn last_segment_is(ty: &Type, name: &str) -> bool {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => {
+            path.segments.last().is_some_and(|s| s.ident == name)
+        }
+        _ => false,
+    }
+}
+
+/// The serialized name given by `#[serde(rename = "..")]` or
+/// `#[serde(rename(serialize = ".."))]`, if any. Anything else is left for serde to check.
+fn serde_rename(attrs: &[Attribute]) -> Option<LitStr> {
+    serde_str(attrs, "rename")
+}
+
+/// The serialized case given by `#[serde(rename_all = "..")]`, like [`serde_rename`].
+fn serde_rename_all(attrs: &[Attribute]) -> Option<LitStr> {
+    serde_str(attrs, "rename_all")
+}
+
+fn serde_str(attrs: &[Attribute], key: &str) -> Option<LitStr> {
+    attrs
+        .iter()
+        .filter(|a| a.path.is_ident("serde"))
+        .filter_map(|a| match a.parse_meta() {
+            Ok(Meta::List(ml)) => Some(ml.nested),
+            _ => None,
+        })
+        .flatten()
+        .find_map(|nm| match nm {
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident(key) => match nv.lit {
+                Lit::Str(s) => Some(s),
+                _ => None,
+            },
+            NestedMeta::Meta(Meta::List(ml)) if ml.path.is_ident(key) => {
+                ml.nested.into_iter().find_map(|nm| match nm {
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(s),
+                        ..
+                    })) if path.is_ident("serialize") => Some(s),
+                    _ => None,
+                })
+            }
+            _ => None,
+        })
+}
+
 fn get_attr(attrs: Vec<Attribute>) -> Option<Attribute> {
     let mut attrs = attrs
         .into_iter()
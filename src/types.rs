@@ -1,5 +1,5 @@
 use std::{iter::Enumerate, str::Chars, vec::IntoIter};
-use syn::LitStr;
+use syn::{Attribute, Ident, LitStr, Member, Path, Type, Visibility};
 
 #[derive(Clone, Copy, Debug)]
 pub enum NumAlign {
@@ -12,6 +12,8 @@ pub enum NumAlign {
 pub enum Tr {
     Upper,
     Lower,
+    UpperVowels,
+    LowerVowels,
     None,
 }
 
@@ -22,17 +24,61 @@ pub enum TrChars {
     FirstMiddleLast(Tr, Tr, Tr),
 }
 
+#[derive(Clone, Copy, Debug)]
+pub enum HeckCase {
+    Snake,
+    Kebab,
+    ShoutySnake,
+    ShoutyKebab,
+    UpperCamel,
+    LowerCamel,
+    Title,
+    Train,
+    JacksonCamel,
+    Pascal,
+}
+
+/// The cases of serde's `rename_all`, applied the way serde applies them.
+#[derive(Clone, Copy, Debug)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
 #[derive(Debug)]
 pub struct Expr {
     pub db: NumAlign,
     pub sep: String,
+    pub first_sep: Option<String>,
+    pub num_sep: Option<String>,
+    pub num_width: Option<usize>,
     pub trs: Vec<TrChars>,
+    pub opts: ExprOpts,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ExprOpts {
+    pub preserve_separators: bool,
+    pub wrap_first: Option<String>,
+    pub wrap_last: Option<String>,
+    pub acronyms: Vec<String>,
+    pub keep_words: Vec<String>,
+    pub keep_leading_underscore: bool,
 }
 
 pub struct ExprParser {
     pub db: NumAlign,
     pub exprs: Vec<TrChars>,
     pub sep: String,
+    pub first_sep: Option<String>,
+    pub num_sep: Option<String>,
+    pub num_width: Option<usize>,
 
     pub input: LitStr,
     pub expr: Option<TrChars>,
@@ -45,3 +91,111 @@ pub struct FievarParser<'a> {
     pub breaks: Vec<usize>,
     pub input: Enumerate<Chars<'a>>,
 }
+
+#[derive(Clone)]
+pub enum Atom {
+    Char(char),
+    Any,
+    Class(bool, Vec<(char, char)>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Repeat {
+    One,
+    Optional,
+    Many,
+    OneOrMore,
+}
+
+#[derive(Clone)]
+pub struct Pattern {
+    pub input: LitStr,
+    pub start: bool,
+    pub end: bool,
+    pub pieces: Vec<(Atom, Repeat)>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum PadAlign {
+    Left,
+    Right,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Pad {
+    pub width: Option<usize>,
+    pub fill: Option<char>,
+    pub align: Option<PadAlign>,
+    pub truncate: bool,
+    pub max_len: Option<usize>,
+    pub max_len_hash: bool,
+}
+
+#[derive(Default)]
+pub struct Container {
+    pub fn_name: Option<Ident>,
+    pub vis: Option<Visibility>,
+    pub impl_trait: Option<Path>,
+    pub module_const: Option<Ident>,
+    pub const_name: Option<Ident>,
+    pub module_const_auto: bool,
+    pub marker: bool,
+    pub set_fields: bool,
+    pub validate: bool,
+    pub cstr: bool,
+    pub phf: bool,
+    pub deascii: bool,
+    pub lines: bool,
+    pub env_aliases: bool,
+    pub pairs: bool,
+    pub bits: bool,
+    pub ordinals: bool,
+    pub sort_alpha: bool,
+    pub computed: Vec<(String, Option<LitStr>)>,
+    pub graphql_safe: bool,
+    pub renamed: bool,
+    pub get: bool,
+    pub types: bool,
+    pub variant_fields: bool,
+    pub keep_leading_underscore: bool,
+    pub strip_common_prefix: bool,
+    pub hash: bool,
+    pub skip_phantom: bool,
+    pub pub_only: bool,
+    pub iter: bool,
+    pub owned: bool,
+    pub array: bool,
+    pub preview: bool,
+    pub sorted: bool,
+    pub deny_duplicates: bool,
+    pub lookup: bool,
+    pub index: bool,
+    pub contains: bool,
+    pub use_serde: bool,
+    pub heck: Option<HeckCase>,
+    pub serde_rule: Option<RenameRule>,
+    pub keep_words: Vec<String>,
+    pub acronyms: Vec<String>,
+    pub only: Option<Vec<LitStr>>,
+    pub title_case: Option<Vec<String>>,
+    pub joined: Option<LitStr>,
+    pub transform: Option<LitStr>,
+    pub index_base: usize,
+    pub qualified_variants: Option<String>,
+    pub ident_safe: Option<String>,
+    pub setters: Option<String>,
+    pub prefix: Option<String>,
+    pub suffix: Option<String>,
+    pub pattern: Option<Pattern>,
+    pub pad: Pad,
+    pub by_type: Vec<(Ident, LitStr)>,
+}
+
+pub struct Item {
+    pub index: usize,
+    pub member: Member,
+    pub attr: Option<Attribute>,
+    pub serde_name: Option<LitStr>,
+    pub ty: Option<Type>,
+    pub inner: Vec<String>,
+}
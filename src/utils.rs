@@ -1,11 +1,13 @@
+use proc_macro2::Span;
 use proc_macro_error::abort;
 use syn::LitStr;
 
 use crate::types::{
-    Expr, ExprParser, FievarParser,
+    Atom, Expr, ExprOpts, ExprParser, FievarParser, HeckCase,
     NumAlign::{self, *},
+    Pattern, RenameRule, Repeat,
     Tr::{self, *},
-    TrChars::*,
+    TrChars::{self, *},
 };
 
 macro_rules! next_or_return {
@@ -19,18 +21,55 @@ macro_rules! next_or_return {
 
 macro_rules! parse_error {
     ($str:expr, $i:expr, $c:expr) => {{
-        abort!($str, "invalid character '{}' at {}", $c, $i)
+        abort!(
+            char_span(&$str, $i),
+            "invalid character '{}' at {}", $c, $i;
+            help = "\n{}\n{}^", $str.value(), " ".repeat($i)
+        )
     }};
 }
 
+/// The span of the `i`th character of `lit`, or of the whole literal where the compiler
+/// can't narrow spans or the literal is written with escapes.
+fn char_span(lit: &LitStr, i: usize) -> Span {
+    let v = lit.value();
+    let src = lit.token().to_string();
+
+    if src.get(1..src.len() - 1) != Some(v.as_str()) {
+        return lit.span();
+    }
+
+    let start = v.char_indices().nth(i).map_or(v.len(), |(b, _)| b) + 1;
+    let end = v[start - 1..]
+        .chars()
+        .next()
+        .map_or(start, |c| start + c.len_utf8());
+    lit.token()
+        .subspan(start..end)
+        .unwrap_or_else(|| lit.span())
+}
+
 impl ExprParser {
     pub fn parse(input: LitStr) -> Expr {
         let v = input.value();
 
-        let (expr, sep) = v
-            .split_once('|')
-            .map(|(e, s)| (e, s.to_string()))
-            .unwrap_or((&v, "".to_string()));
+        // Each `|` not preceded by a `\` starts a separator, and `\|` is a literal `|`.
+        let bars = v
+            .char_indices()
+            .filter(|&(i, c)| c == '|' && !v[..i].ends_with('\\'))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        let part = |a: usize, b: usize| v[a + 1..b].replace("\\|", "|");
+        let (expr, first_sep, sep) = match bars[..] {
+            [] => (v.as_str(), Option::None, "".to_string()),
+            [a] => (&v[..a], Option::None, part(a, v.len())),
+            [a, b] => (&v[..a], Some(part(a, b)), part(b, v.len())),
+            _ => abort!(
+                input,
+                "a transform takes at most two separators";
+                help = "escape a `|` that is part of a separator as `\\|`"
+            ),
+        };
 
         let expr = expr.chars().enumerate().collect::<Vec<_>>().into_iter();
 
@@ -38,6 +77,10 @@ impl ExprParser {
             db: Left,
             exprs: vec![],
             sep,
+            first_sep,
+
+            num_sep: Option::None,
+            num_width: Option::None,
 
             input,
             expr: Option::None,
@@ -49,7 +92,11 @@ impl ExprParser {
         Expr {
             db: m.db,
             sep: m.sep,
+            first_sep: m.first_sep,
+            num_sep: m.num_sep,
+            num_width: m.num_width,
             trs: m.exprs,
+            opts: ExprOpts::default(),
         }
     }
     fn run(&mut self) {
@@ -77,6 +124,14 @@ impl ExprParser {
                 self.expr = Some(All(None));
                 self.all(None);
             }
+            'V' if ec < 3 => {
+                self.expr = Some(All(UpperVowels));
+                self.all(UpperVowels);
+            }
+            'v' if ec < 3 => {
+                self.expr = Some(All(LowerVowels));
+                self.all(LowerVowels);
+            }
             '_' => self.middle_right(),
             ' ' => self.start(),
             '1' => {
@@ -102,6 +157,14 @@ impl ExprParser {
                 self.expr = Some(FirstRest(tr, None));
                 self.first_rest(tr, None);
             }
+            'V' => {
+                self.expr = Some(FirstRest(tr, UpperVowels));
+                self.first_rest(tr, UpperVowels);
+            }
+            'v' => {
+                self.expr = Some(FirstRest(tr, LowerVowels));
+                self.first_rest(tr, LowerVowels);
+            }
             ' ' => {
                 self.exprs.push(All(tr));
                 self.expr = Option::None;
@@ -127,6 +190,14 @@ impl ExprParser {
                 self.expr = Some(FirstMiddleLast(tr1, tr2, None));
                 self.first_middle_last(tr1, tr2, None);
             }
+            'V' => {
+                self.expr = Some(FirstMiddleLast(tr1, tr2, UpperVowels));
+                self.first_middle_last(tr1, tr2, UpperVowels);
+            }
+            'v' => {
+                self.expr = Some(FirstMiddleLast(tr1, tr2, LowerVowels));
+                self.first_middle_last(tr1, tr2, LowerVowels);
+            }
             ' ' => {
                 self.exprs.push(FirstRest(tr1, tr2));
                 self.expr = Option::None;
@@ -154,6 +225,8 @@ impl ExprParser {
 
         match c {
             '_' => self.left(),
+            ':' => self.numeral_width(i),
+            '=' => self.numeral_separator(),
             ' ' => self.end(),
             _ => parse_error!(self.input, i, c),
         }
@@ -163,6 +236,8 @@ impl ExprParser {
 
         match c {
             '_' => self.middle(),
+            ':' => self.numeral_width(i),
+            '=' => self.numeral_separator(),
             ' ' => self.end(),
             _ => parse_error!(self.input, i, c),
         }
@@ -175,10 +250,52 @@ impl ExprParser {
                 self.db = Middle;
                 self.middle();
             }
+            ':' => self.numeral_width(i),
+            '=' => self.numeral_separator(),
             ' ' => self.end(),
             _ => parse_error!(self.input, i, c),
         }
     }
+    fn numeral_width(&mut self, colon: usize) {
+        let mut width = String::new();
+        let next = loop {
+            match self.chars.next() {
+                Some((_, c @ '0'..='9')) => width.push(c),
+                next => break next,
+            }
+        };
+
+        if width.is_empty() {
+            abort!(
+                self.input,
+                "missing numeral width after ':' at {}", colon;
+                help = "expected a width, like `__1:3`"
+            );
+        }
+        self.num_width = match width.parse() {
+            Ok(w) => Some(w),
+            Err(_) => abort!(self.input, "numeral width `{}` is too large", width),
+        };
+
+        match next {
+            Some((_, '=')) => self.numeral_separator(),
+            Some((_, ' ')) => self.end(),
+            Some((i, c)) => parse_error!(self.input, i, c),
+            Option::None => {}
+        }
+    }
+    fn numeral_separator(&mut self) {
+        let sep = self.num_sep.get_or_insert_with(String::new);
+        let (_, c) = next_or_return!(self.chars);
+
+        match c {
+            ' ' => self.end(),
+            _ => {
+                sep.push(c);
+                self.numeral_separator();
+            }
+        }
+    }
     fn middle_right(&mut self) {
         let (i, c) = next_or_return!(self.chars);
 
@@ -195,8 +312,11 @@ impl ExprParser {
 }
 
 impl<'a> FievarParser<'a> {
-    pub fn parse(s: &'a str, db: NumAlign) -> Vec<&str> {
-        let last = s.len() - 1;
+    pub fn spans(s: &'a str, db: NumAlign) -> Vec<(usize, usize)> {
+        let last = match s.chars().count() {
+            0 => return vec![],
+            n => n - 1,
+        };
         let mut m = Self {
             db,
             input: s.chars().enumerate(),
@@ -207,104 +327,171 @@ impl<'a> FievarParser<'a> {
 
         let len = m.breaks.len();
 
-        if len % 2 != 0 {
+        if len % 2 == 1 {
             m.breaks.push(last);
         }
 
+        // The breaks are char indices, the spans are inclusive byte indices.
+        let chars = s.char_indices().collect::<Vec<_>>();
         let mut r = vec![];
         for i in (0..len).step_by(2) {
-            r.push(&s[m.breaks[i]..=m.breaks[i + 1]]);
+            let (b, _) = chars[m.breaks[i]];
+            let (e, c) = chars[m.breaks[i + 1]];
+            r.push((b, e + c.len_utf8() - 1));
         }
         r
     }
+    /// Like [`Self::spans`], except that any word in `keep` found in `s` becomes a word of its
+    /// own. Each span is tagged with whether it came from `keep`.
+    pub fn spans_keeping(s: &'a str, db: NumAlign, keep: &[String]) -> Vec<(usize, usize, bool)> {
+        let mut r = vec![];
+        let mut init = 0;
+        let mut i = 0;
+        let rest = |r: &mut Vec<_>, b: usize, e: usize| {
+            r.extend(
+                Self::spans(&s[b..e], db)
+                    .into_iter()
+                    .map(|(sb, se)| (b + sb, b + se, false)),
+            )
+        };
+
+        while i < s.len() {
+            let kept = keep
+                .iter()
+                .filter(|k| !k.is_empty() && s[i..].starts_with(k.as_str()))
+                .max_by_key(|k| k.len());
+
+            match kept {
+                Some(k) => {
+                    rest(&mut r, init, i);
+                    r.push((i, i + k.len() - 1, true));
+                    i += k.len();
+                    init = i;
+                }
+                Option::None => i += s[i..].chars().next().unwrap().len_utf8(),
+            }
+        }
+
+        rest(&mut r, init, s.len());
+        r
+    }
     fn start(&mut self) {
         let (i, c) = next_or_return!(self.input);
 
         match c {
-            '_' => self.under(),
-            'A'..='Z' => self.push_next(&[i], Self::upper),
-            'a'..='z' => self.push_next(&[i], Self::lower),
-            _ => unreachable!(),
+            '_' | '-' => self.under(),
+            c if c.is_uppercase() => self.push_next(&[i], Self::upper),
+            '0'..='9' => self.push_next(&[i], Self::leading_digit),
+            _ => self.push_next(&[i], Self::lower),
         }
     }
     fn under(&mut self) {
         let (i, c) = next_or_return!(self.input);
 
         match c {
-            '_' => self.under(),
-            'A'..='Z' => self.push_next(&[i], Self::upper),
-            'a'..='z' => self.push_next(&[i], Self::lower),
+            '_' | '-' => self.under(),
+            c if c.is_uppercase() => self.push_next(&[i], Self::upper),
             '0'..='9' => self.push_next(&[i], Self::digit),
-            _ => unreachable!(),
+            _ => self.push_next(&[i], Self::lower),
         }
     }
     fn upper(&mut self) {
         let (i, c) = next_or_return!(self.input);
 
         match c {
-            '_' => self.push_next(&[i - 1], Self::under),
-            'A'..='Z' => self.upper_upper(),
-            'a'..='z' => self.lower(),
+            '_' | '-' => self.push_next(&[i - 1], Self::under),
+            c if c.is_uppercase() => self.upper_upper(),
+            c if c.is_lowercase() => self.lower(),
             '0'..='9' => self.alphabet_digit(i),
-            _ => unreachable!(),
+            _ => self.upper(),
         }
     }
     fn upper_upper(&mut self) {
         let (i, c) = next_or_return!(self.input);
 
         match c {
-            '_' => self.push_next(&[i - 1], Self::under),
-            'A'..='Z' => self.upper_upper(),
-            'a'..='z' => self.push_next(&[i - 2, i - 1], Self::lower),
+            '_' | '-' => self.push_next(&[i - 1], Self::under),
+            c if c.is_uppercase() => self.upper_upper(),
+            c if c.is_lowercase() => self.push_next(&[i - 2, i - 1], Self::lower),
             '0'..='9' => self.alphabet_digit(i),
-            _ => unreachable!(),
+            _ => self.upper_upper(),
         }
     }
     fn lower(&mut self) {
         let (i, c) = next_or_return!(self.input);
 
         match c {
-            '_' => self.push_next(&[i - 1], Self::under),
-            'A'..='Z' => self.push_next(&[i - 1, i], Self::upper),
-            'a'..='z' => self.lower(),
+            '_' | '-' => self.push_next(&[i - 1], Self::under),
+            c if c.is_uppercase() => self.push_next(&[i - 1, i], Self::upper),
             '0'..='9' => self.alphabet_digit(i),
-            _ => unreachable!(),
+            _ => self.lower(),
         }
     }
     fn alphabet_digit(&mut self, ds: usize) {
-        let (i, c) = next_or_return!(self.input);
+        let (i, c) = match self.input.next() {
+            Some(v) => v,
+            // Trailing numerals are still a word of their own.
+            _ => {
+                if let Middle = self.db {
+                    self.breaks.extend_from_slice(&[ds - 1, ds]);
+                }
+                return;
+            }
+        };
 
         match c {
-            '_' => self.push_next(&[i - 1], Self::under),
-            'A'..='Z' => match self.db {
+            '_' | '-' => match self.db {
+                Middle => self.push_next(&[ds - 1, ds, i - 1], Self::under),
+                _ => self.push_next(&[i - 1], Self::under),
+            },
+            c if c.is_uppercase() => match self.db {
                 Left => self.push_next(&[i - 1, i], Self::upper),
                 Middle => self.push_next(&[ds - 1, ds, i - 1, i], Self::upper),
                 Right => self.push_next(&[ds - 1, ds], Self::upper),
             },
-            'a'..='z' => match self.db {
+            c if c.is_lowercase() => match self.db {
                 Left => self.push_next(&[i - 1, i], Self::lower),
                 Middle => self.push_next(&[ds - 1, ds, i - 1, i], Self::lower),
                 Right => self.push_next(&[ds - 1, ds], Self::lower),
             },
             '0'..='9' => self.alphabet_digit(ds),
-            _ => unreachable!(),
+            _ => self.lower(),
         }
     }
     fn digit(&mut self) {
         let (i, c) = next_or_return!(self.input);
 
         match c {
-            '_' => self.push_next(&[i - 1], Self::under),
-            'A'..='Z' => match self.db {
+            '_' | '-' => self.push_next(&[i - 1], Self::under),
+            c if c.is_uppercase() => match self.db {
                 Right => self.upper(),
                 _ => self.push_next(&[i - 1, i], Self::upper),
             },
-            'a'..='z' => match self.db {
+            c if c.is_lowercase() => match self.db {
                 Right => self.lower(),
                 _ => self.push_next(&[i - 1, i], Self::lower),
             },
             '0'..='9' => self.digit(),
-            _ => unreachable!(),
+            _ => self.lower(),
+        }
+    }
+    /// Numerals at the very start of the name. No word comes before them, so only `Middle`
+    /// sets them apart.
+    fn leading_digit(&mut self) {
+        let (i, c) = next_or_return!(self.input);
+
+        match c {
+            '_' | '-' => self.push_next(&[i - 1], Self::under),
+            c if c.is_uppercase() => match self.db {
+                Middle => self.push_next(&[i - 1, i], Self::upper),
+                _ => self.upper(),
+            },
+            c if c.is_lowercase() => match self.db {
+                Middle => self.push_next(&[i - 1, i], Self::lower),
+                _ => self.lower(),
+            },
+            '0'..='9' => self.leading_digit(),
+            _ => self.lower(),
         }
     }
     fn push_next(&mut self, i: &[usize], f: fn(&mut FievarParser<'a>)) {
@@ -312,3 +499,528 @@ impl<'a> FievarParser<'a> {
         f(self);
     }
 }
+
+impl Pattern {
+    /// Parses a small subset of regular expressions: literals, `.`, `[...]` classes with
+    /// ranges and `^` negation, `\\` escapes, the `?`, `*` and `+` quantifiers, and `^`/`$`
+    /// anchors.
+    pub fn parse(input: LitStr) -> Pattern {
+        let v = input.value();
+        let mut chars = v.chars().enumerate().peekable();
+        let mut pieces = vec![];
+        let mut start = false;
+        let mut end = false;
+
+        while let Some((i, c)) = chars.next() {
+            if end {
+                parse_error!(input, i - 1, '$');
+            }
+
+            let atom = match c {
+                '^' if i == 0 => {
+                    start = true;
+                    continue;
+                }
+                '$' => {
+                    end = true;
+                    continue;
+                }
+                '.' => Atom::Any,
+                '\\' => match chars.next() {
+                    Some((_, c)) => Atom::Char(c),
+                    Option::None => parse_error!(input, i, c),
+                },
+                '[' => {
+                    let neg = chars.next_if(|&(_, c)| c == '^').is_some();
+                    let mut ranges = vec![];
+
+                    loop {
+                        let c = match chars.next() {
+                            Some((_, ']')) if !ranges.is_empty() => break,
+                            Some((_, '\\')) => match chars.next() {
+                                Some((_, c)) => c,
+                                Option::None => parse_error!(input, i, '['),
+                            },
+                            Some((_, c)) => c,
+                            Option::None => parse_error!(input, i, '['),
+                        };
+
+                        match chars.next_if(|&(_, c)| c == '-') {
+                            Some(_) if chars.peek().is_some_and(|&(_, c)| c != ']') => {
+                                let (j, to) = chars.next().unwrap();
+                                if to < c {
+                                    parse_error!(input, j, to);
+                                }
+                                ranges.push((c, to));
+                            }
+                            Some(_) => {
+                                ranges.push((c, c));
+                                ranges.push(('-', '-'));
+                            }
+                            Option::None => ranges.push((c, c)),
+                        }
+                    }
+
+                    Atom::Class(neg, ranges)
+                }
+                '?' | '*' | '+' | '(' | ')' | '|' | '{' | '}' => parse_error!(input, i, c),
+                c => Atom::Char(c),
+            };
+
+            let repeat = match chars.next_if(|&(_, c)| matches!(c, '?' | '*' | '+')) {
+                Some((_, '?')) => Repeat::Optional,
+                Some((_, '*')) => Repeat::Many,
+                Some((_, '+')) => Repeat::OneOrMore,
+                _ => Repeat::One,
+            };
+
+            pieces.push((atom, repeat));
+        }
+
+        Pattern {
+            input,
+            start,
+            end,
+            pieces,
+        }
+    }
+
+    pub fn is_match(&self, s: &str) -> bool {
+        let chars = s.chars().collect::<Vec<_>>();
+
+        match self.start {
+            true => self.match_at(&chars, 0, 0),
+            false => (0..=chars.len()).any(|i| self.match_at(&chars, i, 0)),
+        }
+    }
+
+    fn match_at(&self, chars: &[char], i: usize, p: usize) -> bool {
+        let (atom, repeat) = match self.pieces.get(p) {
+            Some(v) => v,
+            Option::None => return !self.end || i == chars.len(),
+        };
+
+        let matches = |c: char| match atom {
+            Atom::Char(a) => *a == c,
+            Atom::Any => true,
+            Atom::Class(neg, ranges) => ranges.iter().any(|&(b, e)| (b..=e).contains(&c)) != *neg,
+        };
+
+        let (min, max) = match repeat {
+            Repeat::One => (1, 1),
+            Repeat::Optional => (0, 1),
+            Repeat::Many => (0, usize::MAX),
+            Repeat::OneOrMore => (1, usize::MAX),
+        };
+
+        let run = chars[i..]
+            .iter()
+            .take(max)
+            .take_while(|&&c| matches(c))
+            .count();
+
+        (min..=run)
+            .rev()
+            .any(|n| self.match_at(chars, i + n, p + 1))
+    }
+}
+
+/// Describes what `expr` does in prose.
+pub fn describe(expr: &Expr) -> String {
+    fn tr(tr: Tr, target: &str) -> String {
+        match tr {
+            Upper => format!("uppercase {}", target),
+            Lower => format!("lowercase {}", target),
+            UpperVowels => format!(
+                "uppercase the vowels and lowercase the consonants of {}",
+                target
+            ),
+            LowerVowels => format!(
+                "lowercase the vowels and uppercase the consonants of {}",
+                target
+            ),
+            None => format!("keep {}", target),
+        }
+    }
+
+    fn chars(trs: &TrChars, target: &str) -> String {
+        match *trs {
+            All(t) => tr(t, target),
+            FirstRest(f, r) => format!(
+                "{} and {} of {}",
+                tr(f, "the first letter"),
+                tr(r, "the other letters"),
+                target
+            ),
+            FirstMiddleLast(f, m, l) => format!(
+                "{}, {} and {} of {}",
+                tr(f, "the first letter"),
+                tr(m, "the middle letters"),
+                tr(l, "the last letter"),
+                target
+            ),
+        }
+    }
+
+    let mut parts = match expr.trs.as_slice() {
+        [] => vec!["keep all words".to_string()],
+        [a] => vec![chars(a, "all words")],
+        [f, r] => vec![chars(f, "the first word"), chars(r, "the other words")],
+        [f, m, l, ..] => vec![
+            chars(f, "the first word"),
+            chars(m, "the middle words"),
+            chars(l, "the last word"),
+        ],
+    };
+
+    if let Some(s) = &expr.first_sep {
+        parts.push(match s.as_str() {
+            "" => "join the first two words without a separator".to_string(),
+            s => format!("join the first two words with '{}'", s),
+        });
+    }
+
+    parts.push(match expr.sep.as_str() {
+        "" => "join without a separator".to_string(),
+        s => format!("join with '{}'", s),
+    });
+
+    parts.push(
+        match expr.db {
+            Left => "keep numerals with the word before them",
+            Right => "keep numerals with the word after them",
+            Middle => "keep numerals as words of their own",
+        }
+        .to_string(),
+    );
+
+    if let Some(s) = &expr.num_sep {
+        parts.push(format!("join numerals with '{}'", s));
+    }
+
+    if let Some(w) = expr.num_width {
+        parts.push(format!("zero-pad numerals to {} digits", w));
+    }
+
+    parts.join(", ")
+}
+
+/// 64-bit FNV-1a over `names`, each followed by a `0xff` byte which never occurs in UTF-8.
+pub fn fnv(names: &[String]) -> u64 {
+    let mut h: u64 = 0xcbf2_9ce4_8422_2325;
+
+    for b in names
+        .iter()
+        .flat_map(|n| n.bytes().chain(std::iter::once(0xff)))
+    {
+        h ^= b as u64;
+        h = h.wrapping_mul(0x0100_0000_01b3);
+    }
+
+    h
+}
+
+/// Splits `s` into words the same way the `heck` crate does.
+pub fn heck_words(s: &str) -> Vec<&str> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Mode {
+        Boundary,
+        Lower,
+        Upper,
+    }
+
+    let mut words = vec![];
+
+    for word in s.split(|c: char| !c.is_alphanumeric()) {
+        let mut chars = word.char_indices().peekable();
+        let mut init = 0;
+        let mut mode = Mode::Boundary;
+
+        while let Some((i, c)) = chars.next() {
+            let (next_i, next) = match chars.peek() {
+                Some(&v) => v,
+                Option::None => {
+                    words.push(&word[init..]);
+                    break;
+                }
+            };
+
+            let next_mode = if c.is_lowercase() {
+                Mode::Lower
+            } else if c.is_uppercase() {
+                Mode::Upper
+            } else {
+                mode
+            };
+
+            if next_mode == Mode::Lower && next.is_uppercase() {
+                words.push(&word[init..next_i]);
+                init = next_i;
+                mode = Mode::Boundary;
+            } else if mode == Mode::Upper && c.is_uppercase() && next.is_lowercase() {
+                words.push(&word[init..i]);
+                init = i;
+                mode = Mode::Boundary;
+            } else {
+                mode = next_mode;
+            }
+        }
+    }
+
+    words
+}
+
+/// Splits `s` like [`heck_words`], except that any word in `keep` found in `s` becomes a word of
+/// its own. Each word is paired with whether it came from `keep`.
+pub fn heck_words_keeping<'a>(s: &'a str, keep: &[String]) -> Vec<(&'a str, bool)> {
+    let mut words = vec![];
+    let mut init = 0;
+    let mut i = 0;
+
+    while i < s.len() {
+        let kept = keep
+            .iter()
+            .filter(|k| !k.is_empty() && s[i..].starts_with(k.as_str()))
+            .max_by_key(|k| k.len());
+
+        match kept {
+            Some(k) => {
+                words.extend(heck_words(&s[init..i]).into_iter().map(|w| (w, false)));
+                words.push((&s[i..i + k.len()], true));
+                i += k.len();
+                init = i;
+            }
+            Option::None => i += s[i..].chars().next().unwrap().len_utf8(),
+        }
+    }
+
+    words.extend(heck_words(&s[init..]).into_iter().map(|w| (w, false)));
+    words
+}
+
+/// The letters with diacritics of the Latin-1 Supplement and Latin Extended-A blocks, as
+/// ranges of characters sharing the same base letter.
+const DIACRITICS: &[(char, char, char)] = &[
+    ('À', 'Å', 'A'),
+    ('à', 'å', 'a'),
+    ('Ç', 'Ç', 'C'),
+    ('ç', 'ç', 'c'),
+    ('È', 'Ë', 'E'),
+    ('è', 'ë', 'e'),
+    ('Ì', 'Ï', 'I'),
+    ('ì', 'ï', 'i'),
+    ('Ñ', 'Ñ', 'N'),
+    ('ñ', 'ñ', 'n'),
+    ('Ò', 'Ö', 'O'),
+    ('ò', 'ö', 'o'),
+    ('Ù', 'Ü', 'U'),
+    ('ù', 'ü', 'u'),
+    ('Ý', 'Ý', 'Y'),
+    ('ý', 'ý', 'y'),
+    ('ÿ', 'ÿ', 'y'),
+    ('Ā', 'ą', 'A'),
+    ('Ć', 'č', 'C'),
+    ('Ď', 'ď', 'D'),
+    ('Ē', 'ě', 'E'),
+    ('Ĝ', 'ģ', 'G'),
+    ('Ĥ', 'ĥ', 'H'),
+    ('Ĩ', 'İ', 'I'),
+    ('Ĵ', 'ĵ', 'J'),
+    ('Ķ', 'ķ', 'K'),
+    ('Ĺ', 'ľ', 'L'),
+    ('Ń', 'ň', 'N'),
+    ('Ō', 'ő', 'O'),
+    ('Ŕ', 'ř', 'R'),
+    ('Ś', 'š', 'S'),
+    ('Ţ', 'ť', 'T'),
+    ('Ũ', 'ų', 'U'),
+    ('Ŵ', 'ŵ', 'W'),
+    ('Ŷ', 'Ÿ', 'Y'),
+    ('Ź', 'ž', 'Z'),
+];
+
+/// Replaces the letters in [`DIACRITICS`] by their base letter and drops combining marks.
+/// Any other character is kept.
+pub fn fold_latin(s: &str) -> String {
+    s.chars()
+        .filter(|c| !('\u{300}'..='\u{36f}').contains(c))
+        .map(
+            |c| match DIACRITICS.iter().find(|(b, e, _)| (*b..=*e).contains(&c)) {
+                Some(&(_, _, base)) if c.is_lowercase() => base.to_ascii_lowercase(),
+                Some(&(_, _, base)) => base,
+                Option::None => c,
+            },
+        )
+        .collect()
+}
+
+/// Words that [`title_case`] leaves lowercase by default.
+pub const SMALL_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "in", "nor", "of", "on", "or", "the", "to",
+    "with",
+];
+
+/// Capitalizes every word and joins them with spaces, except that words in `small` stay
+/// lowercase unless they are the first or last word.
+pub fn title_case(s: &str, small: &[String]) -> String {
+    let words = heck_words(s);
+    let last = words.len().saturating_sub(1);
+
+    words
+        .iter()
+        .enumerate()
+        .map(|(i, w)| {
+            let w = w.to_lowercase();
+            if i != 0 && i != last && small.contains(&w) {
+                return w;
+            }
+
+            let mut c = w.chars();
+            match c.next() {
+                Some(f) => f.to_uppercase().chain(c).collect(),
+                Option::None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Splits `w` before every letter that follows a digit, so `v2config` becomes `v2` and
+/// `config`.
+fn split_after_digits(w: &str) -> Vec<&str> {
+    let mut words = vec![];
+    let mut init = 0;
+    let mut prev_digit = false;
+
+    for (i, c) in w.char_indices() {
+        if prev_digit && c.is_alphabetic() {
+            words.push(&w[init..i]);
+            init = i;
+        }
+        prev_digit = c.is_ascii_digit();
+    }
+
+    words.push(&w[init..]);
+    words
+}
+
+/// Whether `w` is an acronym, that is more than one character and no lowercase letters.
+fn is_acronym(w: &str) -> bool {
+    w.chars().nth(1).is_some()
+        && w.chars().any(char::is_uppercase)
+        && !w.chars().any(char::is_lowercase)
+}
+
+pub fn heck(s: &str, case: HeckCase, keep: &[String]) -> String {
+    let capitalize = |w: &str| {
+        let mut c = w.chars();
+        match c.next() {
+            Some(f) => f
+                .to_uppercase()
+                .chain(c.flat_map(char::to_lowercase))
+                .collect(),
+            Option::None => String::new(),
+        }
+    };
+
+    let mut words = heck_words_keeping(s, keep);
+    if let HeckCase::Pascal = case {
+        words = words
+            .into_iter()
+            .flat_map(|(w, kept)| match kept {
+                true => vec![(w, kept)],
+                false => split_after_digits(w)
+                    .into_iter()
+                    .map(|w| (w, false))
+                    .collect(),
+            })
+            .collect();
+    }
+
+    let words = words.iter().enumerate().map(|(i, &(w, kept))| match case {
+        _ if kept => w.to_string(),
+        HeckCase::JacksonCamel if is_acronym(w) => w.to_string(),
+        HeckCase::JacksonCamel if i == 0 => w.to_lowercase(),
+        HeckCase::Snake | HeckCase::Kebab => w.to_lowercase(),
+        HeckCase::ShoutySnake | HeckCase::ShoutyKebab => w.to_uppercase(),
+        HeckCase::LowerCamel if i == 0 => w.to_lowercase(),
+        HeckCase::UpperCamel
+        | HeckCase::LowerCamel
+        | HeckCase::JacksonCamel
+        | HeckCase::Pascal
+        | HeckCase::Title
+        | HeckCase::Train => capitalize(w),
+    });
+
+    let sep = match case {
+        HeckCase::Snake | HeckCase::ShoutySnake => "_",
+        HeckCase::Kebab | HeckCase::ShoutyKebab | HeckCase::Train => "-",
+        HeckCase::Title => " ",
+        HeckCase::UpperCamel | HeckCase::LowerCamel | HeckCase::JacksonCamel | HeckCase::Pascal => {
+            ""
+        }
+    };
+
+    words.collect::<Vec<_>>().join(sep)
+}
+
+/// Applies `rule` to a variant name, which serde expects to be in PascalCase.
+pub fn serde_variant(s: &str, rule: RenameRule) -> String {
+    let snake = || {
+        let mut r = String::new();
+        for (i, c) in s.chars().enumerate() {
+            if i > 0 && c.is_uppercase() {
+                r.push('_');
+            }
+            r.push(c.to_ascii_lowercase());
+        }
+        r
+    };
+
+    match rule {
+        RenameRule::Pascal => s.to_string(),
+        RenameRule::Lower => s.to_ascii_lowercase(),
+        RenameRule::Upper => s.to_ascii_uppercase(),
+        RenameRule::Camel => lower_first(s),
+        RenameRule::Snake => snake(),
+        RenameRule::ScreamingSnake => snake().to_ascii_uppercase(),
+        RenameRule::Kebab => snake().replace('_', "-"),
+        RenameRule::ScreamingKebab => snake().to_ascii_uppercase().replace('_', "-"),
+    }
+}
+
+/// Applies `rule` to a field name, which serde expects to be in snake_case.
+pub fn serde_field(s: &str, rule: RenameRule) -> String {
+    let pascal = || {
+        let mut r = String::new();
+        let mut capitalize = true;
+        for c in s.chars() {
+            match c {
+                '_' => capitalize = true,
+                c if capitalize => {
+                    r.push(c.to_ascii_uppercase());
+                    capitalize = false;
+                }
+                c => r.push(c),
+            }
+        }
+        r
+    };
+
+    match rule {
+        RenameRule::Lower | RenameRule::Snake => s.to_string(),
+        RenameRule::Upper | RenameRule::ScreamingSnake => s.to_ascii_uppercase(),
+        RenameRule::Pascal => pascal(),
+        RenameRule::Camel => lower_first(&pascal()),
+        RenameRule::Kebab => s.replace('_', "-"),
+        RenameRule::ScreamingKebab => s.to_ascii_uppercase().replace('_', "-"),
+    }
+}
+
+fn lower_first(s: &str) -> String {
+    let mut c = s.chars();
+    match c.next() {
+        Some(f) => format!("{}{}", f.to_ascii_lowercase(), c.as_str()),
+        Option::None => String::new(),
+    }
+}
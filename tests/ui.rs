@@ -0,0 +1,187 @@
+//! Builds small crates against fievar and checks the compiler's messages, for the
+//! diagnostics a doc test can't look at.
+
+use std::{fs, path::PathBuf, process::Command};
+
+/// Builds `src` as the `main.rs` of a crate named `name` and returns what the compiler
+/// printed, one message per line.
+fn build(name: &str, src: &str) -> String {
+    let tmp = PathBuf::from(env!("CARGO_TARGET_TMPDIR"));
+    let dir = tmp.join(name);
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(
+        dir.join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"{}\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n\
+             [dependencies]\nfievar = {{ path = {:?} }}\n\n[workspace]\n",
+            name,
+            env!("CARGO_MANIFEST_DIR"),
+        ),
+    )
+    .unwrap();
+    fs::write(dir.join("src/main.rs"), src).unwrap();
+
+    let out = Command::new(env!("CARGO"))
+        .args(["build", "--offline", "--quiet", "--message-format=short"])
+        .env("CARGO_TARGET_DIR", tmp.join("ui-target"))
+        .current_dir(&dir)
+        .output()
+        .unwrap();
+    String::from_utf8(out.stderr).unwrap()
+}
+
+#[test]
+fn preview_lists_each_name() {
+    let out = build(
+        "preview",
+        r#"
+use fievar::Fields;
+
+#[derive(Fields)]
+#[fievar(preview, transform = "c Cc")]
+struct File {
+    mime_type: String,
+    id: u64,
+}
+
+fn main() {
+    let _ = File::fields();
+}
+"#,
+    );
+
+    assert!(
+        out.contains("fievar preview of File: `mime_type` -> `mimeType`, `id` -> `id`"),
+        "{}",
+        out
+    );
+}
+
+#[test]
+fn list_for_a_key_suggests_a_value() {
+    let out = build(
+        "list_for_a_key",
+        r#"
+use fievar::Fields;
+
+#[derive(Fields)]
+struct File {
+    #[fievar(name("x"))]
+    id: String,
+}
+
+fn main() {}
+"#,
+    );
+
+    assert!(out.contains("unexpected list `name(\"x\")`"), "{}", out);
+    assert!(out.contains("help: try `name = \"x\"`"), "{}", out);
+}
+
+#[test]
+fn duplicate_names_both_offenders() {
+    let out = build(
+        "duplicate_names",
+        r#"
+use fievar::Fields;
+
+#[derive(Fields)]
+#[fievar(deny_duplicates)]
+struct User {
+    user_id: u64,
+    #[fievar(name = "user_id")]
+    id: u64,
+}
+
+fn main() {}
+"#,
+    );
+
+    assert!(
+        out.contains("duplicate name `user_id` for `user_id` and `id`"),
+        "{}",
+        out
+    );
+    assert!(
+        out.contains("note: `user_id` is also named `user_id` here"),
+        "{}",
+        out
+    );
+}
+
+#[test]
+fn transform_error_points_at_the_character() {
+    let out = build(
+        "transform_error",
+        r#"
+use fievar::Fields;
+
+#[derive(Fields)]
+struct File {
+    #[fievar(transform = "c Cx")]
+    mime_type: String,
+}
+
+fn main() {}
+"#,
+    );
+
+    assert!(out.contains("invalid character 'x' at 3"), "{}", out);
+    // The help repeats the transform with a caret under the offending character.
+    let lines = out.lines().collect::<Vec<_>>();
+    let at = lines.iter().position(|l| l.trim() == "c Cx").expect(&out);
+    let col = lines[at].find('c').unwrap();
+    assert_eq!(Some(col + 3), lines[at + 1].find('^'), "{}", out);
+}
+
+#[test]
+fn variants_on_a_struct_suggests_fields() {
+    let out = build(
+        "variants_on_struct",
+        r#"
+use fievar::Variants;
+
+#[derive(Variants)]
+struct File {
+    id: String,
+}
+
+fn main() {}
+"#,
+    );
+
+    assert!(
+        out.contains("`Variants` can only be derived for enums"),
+        "{}",
+        out
+    );
+    assert!(
+        out.contains("help: use `#[derive(Fields)]` for structs"),
+        "{}",
+        out
+    );
+}
+
+#[test]
+fn container_renames_conflict() {
+    let out = build(
+        "container_renames",
+        r#"
+use fievar::Fields;
+
+#[derive(Fields)]
+#[fievar(rename_all = "snake_case", title_case)]
+struct File {
+    mime_type: String,
+}
+
+fn main() {}
+"#,
+    );
+
+    assert!(
+        out.contains("`rename_all` and `title_case` cannot be used together"),
+        "{}",
+        out
+    );
+}